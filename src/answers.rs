@@ -0,0 +1,122 @@
+// Expected answers, stored in a TOML file with a table per day:
+//
+//     [day-07]
+//     part-one = 1555642
+//     part-two = 5974547
+//
+// Either part may be left out while it's still unknown.
+
+use crate::solution::Answer;
+use crate::utils::Error;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+pub const DEFAULT_ANSWERS_PATH: &str = "answers.toml";
+
+#[derive(Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+struct DayAnswers {
+    part_one: Option<Answer>,
+    part_two: Option<Answer>,
+}
+
+#[derive(Default)]
+pub struct Answers {
+    by_day: HashMap<u8, [Option<Answer>; 2]>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Fail { expected: Answer },
+    Unknown,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "pass"),
+            Verdict::Fail { .. } => write!(f, "fail"),
+            Verdict::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+impl Answers {
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        let contents = fs::read_to_string(path).map_err(|err| Error::Io {
+            path: path.to_path_buf(),
+            source: err,
+        })?;
+        Self::parse(&contents).map_err(|message| Error::InvalidAnswers {
+            path: path.to_path_buf(),
+            message,
+        })
+    }
+
+    fn parse(contents: &str) -> Result<Self, String> {
+        let tables: HashMap<String, DayAnswers> =
+            toml::from_str(contents).map_err(|err| err.message().to_string())?;
+        let mut by_day = HashMap::new();
+
+        for (key, day_answers) in tables {
+            let day = key
+                .strip_prefix("day-")
+                .and_then(|day| day.parse::<u8>().ok())
+                .ok_or_else(|| format!("expected a table named `day-DD`, found `{}`", key))?;
+            by_day.insert(day, [day_answers.part_one, day_answers.part_two]);
+        }
+        Ok(Answers { by_day })
+    }
+
+    pub fn verify(&self, day: u8, answers: &[Answer; 2]) -> [Verdict; 2] {
+        let no_answers = [None, None];
+        let expected_answers = self.by_day.get(&day).unwrap_or(&no_answers);
+        [0, 1].map(|part| match &expected_answers[part] {
+            Some(expected) if expected == &answers[part] => Verdict::Pass,
+            Some(expected) => Verdict::Fail {
+                expected: expected.clone(),
+            },
+            None => Verdict::Unknown,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn verifies_answers_by_day_and_part() {
+        let answers = Answers::parse("[day-05]\npart-one = \"CMZ\"\n\n[day-07]\npart-two = 1\n");
+        let answers = answers.unwrap();
+        let day_five = [Answer::from("CMZ"), Answer::from("MCD")];
+        let day_seven = [Answer::from(1), Answer::from(2)];
+
+        assert_eq!(
+            answers.verify(5, &day_five),
+            [Verdict::Pass, Verdict::Unknown]
+        );
+        assert_eq!(
+            answers.verify(7, &day_seven),
+            [
+                Verdict::Unknown,
+                Verdict::Fail {
+                    expected: Answer::from(1)
+                }
+            ]
+        );
+        assert_eq!(
+            answers.verify(9, &day_seven),
+            [Verdict::Unknown, Verdict::Unknown]
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_tables() {
+        assert!(Answers::parse("[seven]\npart-one = 1\n").is_err());
+    }
+}
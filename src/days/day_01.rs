@@ -0,0 +1,277 @@
+// https://adventofcode.com/2022/day/1
+
+use crate::solution::{Answer, InputReport, Solution};
+use crate::top_k::TopK;
+use crate::utils::{parse_line_groups, parse_number, Error};
+use itertools::Itertools;
+use std::cmp::Reverse;
+use std::fmt;
+use std::iter::Iterator;
+
+// Calories of each item carried by each elf
+pub type Inventory = Vec<Vec<i64>>;
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ElfTotal {
+    // Position of the elf in the inventory, from 0
+    pub idx: usize,
+    pub calories: i64,
+}
+
+// The `count` elves carrying the most calories, most first. Elves carrying as much as each
+// other are ranked in inventory order.
+pub fn top_elves(inventory: &Inventory, count: usize) -> Vec<ElfTotal> {
+    let mut top_elves = TopK::new(count);
+    for (elf_idx, items) in inventory.iter().enumerate() {
+        top_elves.push((items.iter().sum::<i64>(), Reverse(elf_idx)));
+    }
+
+    let ranked = top_elves.into_sorted_vec().into_iter();
+    ranked
+        .map(|(calories, Reverse(idx))| ElfTotal { idx, calories })
+        .collect()
+}
+
+pub fn total_of_top_elves(inventory: &Inventory, count: usize) -> i64 {
+    top_elves(inventory, count)
+        .iter()
+        .map(|elf| elf.calories)
+        .sum()
+}
+
+#[derive(Debug, PartialEq)]
+pub struct ElfStats {
+    pub items: usize,
+    pub total: i64,
+    pub mean: f64,
+    pub max: i64,
+}
+
+impl ElfStats {
+    pub fn of(items: &[i64]) -> Self {
+        let total = items.iter().sum();
+        ElfStats {
+            items: items.len(),
+            total,
+            mean: total as f64 / items.len() as f64,
+            max: items.iter().copied().max().unwrap_or(0),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Bin {
+    // Inclusive on both ends
+    pub start: i64,
+    pub end: i64,
+    pub count: usize,
+}
+
+// Splits the range of the values into `num_bins` bins of (nearly) equal width, counting the
+// values in each
+pub fn histogram(values: &[i64], num_bins: usize) -> Vec<Bin> {
+    let (Some(&min), Some(&max)) = (values.iter().min(), values.iter().max()) else {
+        return Vec::new();
+    };
+    let width = ((max - min + 1) as f64 / num_bins as f64).ceil() as i64;
+    let mut bins: Vec<Bin> = (0..num_bins as i64)
+        .map(|bin_idx| Bin {
+            start: min + bin_idx * width,
+            end: (min + (bin_idx + 1) * width - 1).min(max),
+            count: 0,
+        })
+        .take_while(|bin| bin.start <= max)
+        .collect();
+    for value in values {
+        bins[((value - min) / width) as usize].count += 1;
+    }
+    bins
+}
+
+// The value that `percent`% of the (sorted) values are at or below, by nearest rank
+pub fn percentile(sorted_values: &[i64], percent: u8) -> i64 {
+    let rank = (percent as f64 / 100.0 * sorted_values.len() as f64).ceil() as usize;
+    sorted_values[rank.max(1) - 1]
+}
+
+// Statistics of how many calories each elf carries, and of how the totals are distributed
+pub struct CalorieReport {
+    pub elves: Vec<ElfStats>,
+    pub median: f64,
+    pub percentiles: Vec<(u8, i64)>,
+    pub histogram: Vec<Bin>,
+}
+
+impl InputReport for CalorieReport {
+    type Solver = Solver;
+    const NAME: &'static str = "calories";
+    const ABOUT: &'static str = "Items and calories of each elf, and how the totals are spread";
+
+    fn of(inventory: &Inventory) -> Self {
+        let elves = inventory
+            .iter()
+            .map(|items| ElfStats::of(items))
+            .collect_vec();
+        let totals = elves.iter().map(|elf| elf.total).sorted().collect_vec();
+
+        let mid = totals.len() / 2;
+        let median = match totals.len() % 2 {
+            _ if totals.is_empty() => 0.0,
+            0 => (totals[mid - 1] + totals[mid]) as f64 / 2.0,
+            _ => totals[mid] as f64,
+        };
+        let percentiles = match totals.is_empty() {
+            true => Vec::new(),
+            false => [10, 25, 75, 90, 99]
+                .map(|percent| (percent, percentile(&totals, percent)))
+                .to_vec(),
+        };
+
+        CalorieReport {
+            median,
+            percentiles,
+            histogram: histogram(&totals, 10),
+            elves,
+        }
+    }
+}
+
+impl fmt::Display for CalorieReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{:>5}{:>7}{:>9}{:>10}{:>8}",
+            "Elf", "Items", "Total", "Mean", "Max"
+        )?;
+        for (elf_idx, elf) in self.elves.iter().enumerate() {
+            writeln!(
+                f,
+                "{:>5}{:>7}{:>9}{:>10.1}{:>8}",
+                elf_idx + 1,
+                elf.items,
+                elf.total,
+                elf.mean,
+                elf.max
+            )?;
+        }
+
+        writeln!(
+            f,
+            "\n{} elves, median total {:.1}",
+            self.elves.len(),
+            self.median
+        )?;
+        for (percent, total) in &self.percentiles {
+            writeln!(f, "p{:<4}{}", percent, total)?;
+        }
+
+        writeln!(f, "\nTotals")?;
+        let max_count = self
+            .histogram
+            .iter()
+            .map(|bin| bin.count)
+            .max()
+            .unwrap_or(0);
+        for bin in &self.histogram {
+            // Scale the bars to at most 40 characters
+            let bar_len = (bin.count * 40).div_ceil(max_count);
+            let range = format!("{}-{}", bin.start, bin.end);
+            writeln!(f, "{:>13} {:>5} {}", range, bin.count, "#".repeat(bar_len))?;
+        }
+        Ok(())
+    }
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const DAY: u8 = 1;
+    type Input = Inventory;
+
+    fn parse(lines: impl Iterator<Item = String>) -> Result<Self::Input, Error> {
+        parse_line_groups(Self::DAY, lines.enumerate(), |calories| {
+            parse_number(calories, 1)
+        })
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        Answer::from(total_of_top_elves(input, 1))
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        Answer::from(total_of_top_elves(input, 3))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::{solve, solve_reader};
+    use crate::utils::InputSource;
+    use std::path::PathBuf;
+
+    #[test]
+    fn solves_example() {
+        let example = InputSource::Path(PathBuf::from("inputs/examples"));
+        let answers = solve::<Solver>(&example).unwrap().answers;
+        assert_eq!(answers, [Answer::from(24000), Answer::from(45000)]);
+    }
+
+    #[test]
+    fn solves_input() {
+        let answers = solve::<Solver>(&InputSource::Default).unwrap().answers;
+        assert_eq!(answers, [Answer::from(70698), Answer::from(206643)]);
+    }
+
+    #[test]
+    fn solves_input_from_a_reader() {
+        let input = "1000\n2000\n\n4000\n\n500\n";
+        let answers = solve_reader::<Solver>(input.as_bytes()).unwrap().answers;
+        assert_eq!(answers, [Answer::from(4000), Answer::from(7500)]);
+    }
+
+    #[test]
+    fn ranks_top_elves() {
+        let inventory = vec![vec![1, 2], vec![5], vec![3], vec![5]];
+        let ranked = top_elves(&inventory, 3);
+        let expected = [(1, 5), (3, 5), (0, 3)].map(|(idx, calories)| ElfTotal { idx, calories });
+        assert_eq!(ranked, expected);
+    }
+
+    #[test]
+    fn groups_items_by_elf_regardless_of_trailing_blank_lines() {
+        let parse = |input: &str| Solver::parse(input.lines().map(String::from)).unwrap();
+        let inventory = vec![vec![1], vec![2, 3]];
+        assert_eq!(parse("1\n\n2\n3"), inventory);
+        assert_eq!(parse("1\n\n2\n3\n\n"), inventory);
+    }
+
+    #[test]
+    fn reports_calorie_stats() {
+        let example = InputSource::Path(PathBuf::from("inputs/examples/day-01.txt"));
+        let inventory = Solver::parse(example.lines(1).unwrap()).unwrap();
+        let report = CalorieReport::of(&inventory);
+
+        let first_elf = ElfStats {
+            items: 3,
+            total: 6000,
+            mean: 2000.0,
+            max: 3000,
+        };
+        assert_eq!(report.elves[0], first_elf);
+        assert_eq!(report.median, 10000.0);
+        assert_eq!(report.percentiles[1], (25, 6000));
+        assert_eq!(report.percentiles[3], (90, 24000));
+
+        let counts = report.histogram.iter().map(|bin| bin.count).collect_vec();
+        assert_eq!(counts, [2, 0, 1, 1, 0, 0, 0, 0, 0, 1]);
+        assert_eq!(
+            report.histogram[0],
+            Bin {
+                start: 4000,
+                end: 6000,
+                count: 2
+            }
+        );
+    }
+}
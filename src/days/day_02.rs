@@ -0,0 +1,536 @@
+// https://adventofcode.com/2022/day/2
+
+use crate::solution::{Answer, InputReport, Solution};
+use crate::utils::{parse_each_line, split_once, Error, ParseError};
+use itertools::Itertools;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::fmt;
+use std::iter::Iterator;
+
+pub mod simulation;
+
+use simulation::{
+    expected_scores, optimal_score, round_robin, ColumnScores, Fixed, FrequencyCounting,
+    MoveDistribution, Random, Standing, Strategy,
+};
+
+// A move is identified by its position in the rule set it's played under
+#[derive(Eq, PartialEq, Debug, Clone, Copy, Hash)]
+pub struct Move(pub usize);
+
+// The moves of a game, what each scores and which moves each beats. Moves which don't beat each
+// other draw.
+#[derive(Debug, Clone)]
+pub struct RuleSet {
+    names: Vec<String>,
+    scores: Vec<i32>,
+    // `beats[a][b]` is whether move `a` beats move `b`
+    beats: Vec<Vec<bool>>,
+}
+
+impl RuleSet {
+    pub fn new(moves: &[(&str, i32)], wins: &[(&str, &str)]) -> Result<Self, String> {
+        let names = moves.iter().map(|(name, _)| name.to_string()).collect_vec();
+        if let Some(name) = names.iter().duplicates().next() {
+            return Err(format!("move `{}` is given more than once", name));
+        }
+        let scores = moves.iter().map(|(_, score)| *score).collect_vec();
+        let mut beats = vec![vec![false; moves.len()]; moves.len()];
+        let move_idx = |name: &str| {
+            names
+                .iter()
+                .position(|move_name| move_name == name)
+                .ok_or_else(|| format!("unknown move `{}`", name))
+        };
+
+        for (winner, loser) in wins {
+            let (winner, loser) = (move_idx(winner)?, move_idx(loser)?);
+            if winner == loser || beats[loser][winner] {
+                let (winner, loser) = (&names[winner], &names[loser]);
+                return Err(format!(
+                    "`{}` and `{}` can't both beat each other",
+                    winner, loser
+                ));
+            }
+            beats[winner][loser] = true;
+        }
+
+        // Aiming for any outcome against any move has to be possible (each move draws with
+        // itself)
+        for (move_idx, name) in names.iter().enumerate() {
+            if !beats[move_idx].contains(&true) {
+                return Err(format!("`{}` doesn't beat any move", name));
+            }
+            if !beats.iter().any(|move_beats| move_beats[move_idx]) {
+                return Err(format!("no move beats `{}`", name));
+            }
+        }
+        Ok(RuleSet {
+            names,
+            scores,
+            beats,
+        })
+    }
+
+    // A game with an odd number of moves, in which every move beats the half of the other moves
+    // which come before it (wrapping around), so that no move is better than another
+    pub fn cyclic(moves: &[(&str, i32)]) -> Result<Self, String> {
+        if moves.len().is_multiple_of(2) {
+            return Err(String::from("cyclic games need an odd number of moves"));
+        }
+        let wins = (0..moves.len())
+            .flat_map(|winner| {
+                (1..=moves.len() / 2).map(move |offset| {
+                    let loser = (winner + moves.len() - offset) % moves.len();
+                    (moves[winner].0, moves[loser].0)
+                })
+            })
+            .collect_vec();
+        RuleSet::new(moves, &wins)
+    }
+
+    pub fn rock_paper_scissors() -> Self {
+        RuleSet::cyclic(&[("Rock", 1), ("Paper", 2), ("Scissors", 3)]).unwrap()
+    }
+
+    pub fn rock_paper_scissors_lizard_spock() -> Self {
+        let moves = [
+            ("Rock", 1),
+            ("Spock", 5),
+            ("Paper", 2),
+            ("Lizard", 4),
+            ("Scissors", 3),
+        ];
+        RuleSet::cyclic(&moves).unwrap()
+    }
+
+    pub fn moves(&self) -> impl Iterator<Item = Move> {
+        (0..self.names.len()).map(Move)
+    }
+
+    pub fn move_named(&self, name: &str) -> Option<Move> {
+        self.names
+            .iter()
+            .position(|move_name| move_name == name)
+            .map(Move)
+    }
+
+    pub fn name(&self, move_: Move) -> &str {
+        &self.names[move_.0]
+    }
+
+    pub fn score(&self, move_: Move) -> i32 {
+        self.scores[move_.0]
+    }
+
+    // `Greater` when `move_` beats `other`, `Less` when it loses to it and `Equal` on a draw
+    pub fn compare(&self, move_: Move, other: Move) -> Ordering {
+        match (self.beats[move_.0][other.0], self.beats[other.0][move_.0]) {
+            (true, _) => Ordering::Greater,
+            (_, true) => Ordering::Less,
+            _ => Ordering::Equal,
+        }
+    }
+}
+
+#[derive(Eq, PartialEq, Debug, Clone, Copy)]
+pub enum Outcome {
+    Lose,
+    Draw,
+    Win,
+}
+
+impl Outcome {
+    // The outcome of playing `your_move` against `their_move`
+    pub fn of(rules: &RuleSet, your_move: Move, their_move: Move) -> Self {
+        match rules.compare(your_move, their_move) {
+            Ordering::Less => Outcome::Lose,
+            Ordering::Equal => Outcome::Draw,
+            Ordering::Greater => Outcome::Win,
+        }
+    }
+
+    pub fn score(&self) -> i32 {
+        match self {
+            Outcome::Lose => 0,
+            Outcome::Draw => 3,
+            Outcome::Win => 6,
+        }
+    }
+}
+
+// A line of the strategy guide. The second column is read both as your move (as first thought
+// in part one) and as the outcome to aim for (as in part two).
+#[derive(Eq, PartialEq, Debug, Clone, Copy)]
+pub struct Round {
+    pub their_move: Move,
+    pub your_move: Move,
+    pub outcome: Outcome,
+}
+
+// What the letters in each column of the strategy guide stand for
+#[derive(Debug, Clone)]
+pub struct ColumnMapping {
+    their_moves: HashMap<char, Move>,
+    your_moves: HashMap<char, Move>,
+    outcomes: HashMap<char, Outcome>,
+}
+
+impl ColumnMapping {
+    // Moves are given letters in the order of the rule set, and outcomes in the order of losing,
+    // drawing and winning
+    pub fn new(
+        rules: &RuleSet,
+        their_letters: &str,
+        your_letters: &str,
+        outcome_letters: &str,
+    ) -> Result<Self, String> {
+        // Every letter has to stand for a single move or outcome for each of them to be read
+        for letters in [their_letters, your_letters, outcome_letters] {
+            if let Some(letter) = letters.chars().duplicates().next() {
+                return Err(format!(
+                    "letter `{}` is given more than once in `{}`",
+                    letter, letters
+                ));
+            }
+        }
+        let num_moves = rules.moves().count();
+        let move_letters = |letters: &str| match letters.chars().count() == num_moves {
+            true => Ok(letters.chars().zip(rules.moves()).collect()),
+            false => Err(format!(
+                "expected a letter for each of {} moves, found `{}`",
+                num_moves, letters
+            )),
+        };
+        if outcome_letters.chars().count() != 3 {
+            return Err(format!(
+                "expected a letter for each of 3 outcomes, found `{}`",
+                outcome_letters
+            ));
+        }
+
+        Ok(ColumnMapping {
+            their_moves: move_letters(their_letters)?,
+            your_moves: move_letters(your_letters)?,
+            outcomes: outcome_letters
+                .chars()
+                .zip([Outcome::Lose, Outcome::Draw, Outcome::Win])
+                .collect(),
+        })
+    }
+
+    // A, B and C for the elf's rock, paper and scissors, and X, Y and Z for either your rock, paper
+    // and scissors or for losing, drawing and winning
+    pub fn puzzle() -> Self {
+        ColumnMapping::new(&RuleSet::rock_paper_scissors(), "ABC", "XYZ", "XYZ").unwrap()
+    }
+
+    // Splits a line into the elf's move and the raw letter of the second column
+    fn parse_columns<'a>(&self, line: &'a str) -> Result<(Move, &'a str, usize), ParseError> {
+        let (their_letter, your_letter) = split_once(line, " ")?;
+        let their_move = letter_meaning(their_letter, 1, &self.their_moves)?;
+        Ok((their_move, your_letter, their_letter.len() + 2))
+    }
+
+    // Reads the second column only as your move, as first thought in part one
+    pub fn parse_moves(&self, line: &str) -> Result<(Move, Move), ParseError> {
+        let (their_move, your_letter, your_column) = self.parse_columns(line)?;
+        let your_move = letter_meaning(your_letter, your_column, &self.your_moves)?;
+        Ok((their_move, your_move))
+    }
+
+    // Reads the second column only as the outcome to aim for, as in part two
+    pub fn parse_target(&self, line: &str) -> Result<(Move, Outcome), ParseError> {
+        let (their_move, your_letter, your_column) = self.parse_columns(line)?;
+        let outcome = letter_meaning(your_letter, your_column, &self.outcomes)?;
+        Ok((their_move, outcome))
+    }
+
+    // Reads the second column both ways, for answering both parts
+    pub fn parse_round(&self, line: &str) -> Result<Round, ParseError> {
+        let (their_move, your_move) = self.parse_moves(line)?;
+        let (_, outcome) = self.parse_target(line)?;
+        Ok(Round {
+            their_move,
+            your_move,
+            outcome,
+        })
+    }
+}
+
+fn letter_meaning<T: Copy>(
+    token: &str,
+    column: usize,
+    meanings: &HashMap<char, T>,
+) -> Result<T, ParseError> {
+    let mut chars = token.chars();
+    match (chars.next(), chars.next()) {
+        (Some(letter), None) if meanings.contains_key(&letter) => Ok(meanings[&letter]),
+        _ => {
+            let letters = meanings.keys().sorted().join(", ");
+            let msg = format!("expected one of {}, found `{}`", letters, token);
+            Err(ParseError::new(column, msg))
+        }
+    }
+}
+
+pub fn score_for_round(rules: &RuleSet, (their_move, your_move): (Move, Move)) -> i32 {
+    Outcome::of(rules, your_move, their_move).score() + rules.score(your_move)
+}
+
+// The first move (in rule set order) giving the wanted outcome against `their_move`
+pub fn move_to_play(rules: &RuleSet, their_move: Move, wanted_outcome: Outcome) -> Move {
+    rules
+        .moves()
+        .find(|possible_move| Outcome::of(rules, *possible_move, their_move) == wanted_outcome)
+        .unwrap() // Rule sets make sure every move draws with, beats and loses to some move
+}
+
+// The total score of playing the given moves, as (theirs, yours)
+pub fn score_as_moves(rules: &RuleSet, moves: &[(Move, Move)]) -> i32 {
+    let scores = moves.iter().map(|round| score_for_round(rules, *round));
+    scores.sum()
+}
+
+// The total score of aiming for the given outcomes against the elf's moves
+pub fn score_as_outcomes(rules: &RuleSet, targets: &[(Move, Outcome)]) -> i32 {
+    let scores = targets.iter().map(|(their_move, outcome)| {
+        let your_move = move_to_play(rules, *their_move, *outcome);
+        score_for_round(rules, (*their_move, your_move))
+    });
+    scores.sum()
+}
+
+pub fn part_one(rounds: &[Round]) -> i32 {
+    let moves = rounds
+        .iter()
+        .map(|round| (round.their_move, round.your_move))
+        .collect_vec();
+    score_as_moves(&RuleSet::rock_paper_scissors(), &moves)
+}
+
+pub fn part_two(rounds: &[Round]) -> i32 {
+    let targets = rounds
+        .iter()
+        .map(|round| (round.their_move, round.outcome))
+        .collect_vec();
+    score_as_outcomes(&RuleSet::rock_paper_scissors(), &targets)
+}
+
+// How the strategy guide fares against other ways of playing
+pub struct StrategyReport {
+    pub scores: [i32; 2],
+    pub optimal_score: i32,
+    // Against an opponent playing as often as in the guide, and against a random one
+    pub expected_scores: [ColumnScores; 2],
+    pub standings: Vec<Standing>,
+}
+
+impl InputReport for StrategyReport {
+    type Solver = Solver;
+    const NAME: &'static str = "strategy";
+    const ABOUT: &'static str = "The guide against the best responses and other strategies";
+
+    fn of(rounds: &Vec<Round>) -> Self {
+        let rules = RuleSet::rock_paper_scissors();
+        let their_moves = rounds.iter().map(|round| round.their_move).collect_vec();
+        let distributions = [
+            MoveDistribution::of_moves(&rules, &their_moves),
+            MoveDistribution::uniform(&rules),
+        ];
+        // The guide's and the elf's moves are replayed in the tournament, so it takes a round
+        let guide = Fixed::new(
+            "guide",
+            rounds.iter().map(|round| round.your_move).collect(),
+        );
+        let elf = Fixed::new("elf", their_moves.clone());
+        let mut strategies: Vec<Box<dyn Strategy>> = match (guide, elf) {
+            (Ok(guide), Ok(elf)) => vec![
+                Box::new(guide),
+                Box::new(elf),
+                Box::new(Random::new(2022)),
+                Box::new(FrequencyCounting),
+            ],
+            _ => Vec::new(),
+        };
+
+        StrategyReport {
+            scores: [part_one(rounds), part_two(rounds)],
+            optimal_score: optimal_score(&rules, &their_moves),
+            expected_scores: distributions.map(|dist| expected_scores(&rules, rounds, &dist)),
+            standings: round_robin(&rules, &mut strategies, rounds.len()),
+        }
+    }
+}
+
+impl fmt::Display for StrategyReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{:36}{:>12}{:>14}", "Score", "as moves", "as outcomes")?;
+        writeln!(
+            f,
+            "{:36}{:>12}{:>14}",
+            "following the guide", self.scores[0], self.scores[1]
+        )?;
+        let labels = [
+            "expected vs the elf's frequencies",
+            "expected vs a random elf",
+        ];
+        for (label, expected) in labels.iter().zip(&self.expected_scores) {
+            writeln!(
+                f,
+                "{:36}{:>12.1}{:>14.1}",
+                label, expected.as_moves, expected.as_outcomes
+            )?;
+        }
+        writeln!(
+            f,
+            "{:36}{:>12}",
+            "best responses to the elf", self.optimal_score
+        )?;
+
+        if self.standings.is_empty() {
+            return writeln!(f, "\nNo tournament, as the guide has no rounds");
+        }
+        writeln!(f, "\n{:24}{:>8}  W-D-L", "Tournament", "Score")?;
+        for standing in &self.standings {
+            writeln!(f, "{}", standing)?;
+        }
+        Ok(())
+    }
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const DAY: u8 = 2;
+    type Input = Vec<Round>;
+
+    fn parse(lines: impl Iterator<Item = String>) -> Result<Self::Input, Error> {
+        let mapping = ColumnMapping::puzzle();
+        parse_each_line(Self::DAY, lines.enumerate(), |line| {
+            mapping.parse_round(line)
+        })
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        Answer::from(part_one(input))
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        Answer::from(part_two(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::solve;
+    use crate::utils::InputSource;
+    use std::path::PathBuf;
+
+    #[test]
+    fn solves_example() {
+        let example = InputSource::Path(PathBuf::from("inputs/examples"));
+        let answers = solve::<Solver>(&example).unwrap().answers;
+        assert_eq!(answers, [Answer::from(15), Answer::from(12)]);
+    }
+
+    #[test]
+    fn solves_input() {
+        let answers = solve::<Solver>(&InputSource::Default).unwrap().answers;
+        assert_eq!(answers, [Answer::from(12679), Answer::from(14470)]);
+    }
+
+    #[test]
+    fn scores_rounds_under_any_rule_set() {
+        let rules = RuleSet::rock_paper_scissors_lizard_spock();
+        let [rock, spock, lizard] =
+            ["Rock", "Spock", "Lizard"].map(|name| rules.move_named(name).unwrap());
+        assert_eq!(score_for_round(&rules, (rock, spock)), 6 + 5);
+        assert_eq!(score_for_round(&rules, (spock, lizard)), 6 + 4);
+        assert_eq!(score_for_round(&rules, (lizard, rock)), 1 + 6);
+        assert_eq!(score_for_round(&rules, (rock, rock)), 3 + 1);
+
+        // Every move of a cyclic game beats as many moves as it loses to
+        let rules = RuleSet::cyclic(&[
+            ("A", 1),
+            ("B", 2),
+            ("C", 3),
+            ("D", 4),
+            ("E", 5),
+            ("F", 6),
+            ("G", 7),
+        ])
+        .unwrap();
+        for move_ in rules.moves() {
+            let wins = rules
+                .moves()
+                .filter(|other| rules.compare(move_, *other) == Ordering::Greater);
+            assert_eq!(wins.count(), 3);
+        }
+        assert!(RuleSet::cyclic(&[("A", 1), ("B", 2)]).is_err());
+        assert!(RuleSet::new(&[("A", 1), ("B", 2)], &[("A", "B"), ("B", "A")]).is_err());
+        let err = RuleSet::new(&[("A", 1), ("B", 2)], &[("A", "B")]).unwrap_err();
+        assert_eq!(err, "no move beats `A`");
+        let err = RuleSet::cyclic(&[("A", 1), ("B", 2), ("A", 3)]).unwrap_err();
+        assert_eq!(err, "move `A` is given more than once");
+    }
+
+    #[test]
+    fn reports_malformed_rounds() {
+        let mapping = ColumnMapping::puzzle();
+        let error = |line| mapping.parse_round(line).unwrap_err();
+        assert_eq!(error("A W").column, 3);
+        assert_eq!(error("D X").message, "expected one of A, B, C, found `D`");
+        assert_eq!(error("AX").message, "expected ` `");
+        assert_eq!(error("A XY").column, 3);
+
+        let lines = ["A Y", "B"].map(String::from).into_iter();
+        assert!(matches!(
+            Solver::parse(lines),
+            Err(Error::Parse {
+                line: 2,
+                column: 2,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn reads_columns_with_any_letters() {
+        let rules = RuleSet::rock_paper_scissors_lizard_spock();
+        let err = ColumnMapping::new(&rules, "rSplx", "VWXYZ", "XY").unwrap_err();
+        assert!(err.contains("3 outcomes"));
+        let err = ColumnMapping::new(&rules, "rSplx", "VWXYV", "XYZ").unwrap_err();
+        assert_eq!(err, "letter `V` is given more than once in `VWXYV`");
+
+        let mapping = ColumnMapping::new(&rules, "rSplx", "VWXYZ", "XYZ").unwrap();
+        let round = mapping.parse_round("x X").unwrap();
+        assert_eq!(rules.name(round.their_move), "Scissors");
+        assert_eq!(rules.name(round.your_move), "Paper");
+        assert_eq!(round.outcome, Outcome::Lose);
+        // `V` is a move, but not an outcome, so it can only be read as in part one
+        let (their_move, your_move) = mapping.parse_moves("x V").unwrap();
+        assert_eq!(rules.name(their_move), "Scissors");
+        assert_eq!(rules.name(your_move), "Rock");
+        assert_eq!(score_as_moves(&rules, &[(their_move, your_move)]), 6 + 1);
+        assert_eq!(mapping.parse_target("x V").unwrap_err().column, 3);
+        assert!(mapping.parse_round("x V").is_err());
+
+        let (their_move, outcome) = mapping.parse_target("x Z").unwrap();
+        let score = score_as_outcomes(&rules, &[(their_move, outcome)]);
+        assert_eq!(
+            score,
+            6 + rules.score(move_to_play(&rules, their_move, outcome))
+        );
+    }
+
+    #[test]
+    fn reports_on_empty_guides() {
+        let report = StrategyReport::of(&Vec::new());
+        assert_eq!(report.scores, [0, 0]);
+        assert!(report.standings.is_empty());
+        assert!(report.to_string().contains("No tournament"));
+    }
+}
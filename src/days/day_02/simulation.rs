@@ -0,0 +1,345 @@
+// Playing day 2's game against opponents: the best responses to what they play, and round-robin
+// tournaments between strategies.
+
+use super::{move_to_play, score_for_round, Move, Outcome, Round, RuleSet};
+use itertools::Itertools;
+use std::fmt;
+
+// SplitMix64, which is plenty for simulations and keeps runs reproducible from a seed
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    // Uniform in [0, 1)
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+// How likely an opponent is to play each move, indexed by move
+#[derive(Debug, Clone, PartialEq)]
+pub struct MoveDistribution(pub Vec<f64>);
+
+impl MoveDistribution {
+    pub fn uniform(rules: &RuleSet) -> Self {
+        let num_moves = rules.moves().count();
+        MoveDistribution(vec![1.0 / num_moves as f64; num_moves])
+    }
+
+    // The frequency of each move in `moves`, or uniform when there are none
+    pub fn of_moves(rules: &RuleSet, moves: &[Move]) -> Self {
+        if moves.is_empty() {
+            return MoveDistribution::uniform(rules);
+        }
+        let mut counts = vec![0.0; rules.moves().count()];
+        for move_ in moves {
+            counts[move_.0] += 1.0;
+        }
+        MoveDistribution(
+            counts
+                .iter()
+                .map(|count| count / moves.len() as f64)
+                .collect(),
+        )
+    }
+
+    pub fn sample(&self, rng: &mut Rng) -> Move {
+        let mut threshold = rng.next_f64();
+        for (move_idx, probability) in self.0.iter().enumerate() {
+            threshold -= probability;
+            if threshold < 0.0 {
+                return Move(move_idx);
+            }
+        }
+        Move(self.0.len() - 1)
+    }
+
+    pub fn expected_score(&self, rules: &RuleSet, your_move: Move) -> f64 {
+        let scores = rules
+            .moves()
+            .map(|their_move| score_for_round(rules, (their_move, your_move)));
+        scores
+            .zip(&self.0)
+            .map(|(score, probability)| score as f64 * probability)
+            .sum()
+    }
+}
+
+// The move scoring the most against `their_move`. Winning isn't always best, as a win with a
+// low scoring move can score less than a draw with a high scoring one.
+pub fn best_response(rules: &RuleSet, their_move: Move) -> Move {
+    let score = |your_move: &Move| score_for_round(rules, (their_move, *your_move));
+    rules.moves().max_by_key(score).unwrap()
+}
+
+// The move with the highest expected score against an opponent playing by `distribution`
+pub fn best_response_to(rules: &RuleSet, distribution: &MoveDistribution) -> Move {
+    let expected_score = |your_move: &Move| distribution.expected_score(rules, *your_move);
+    rules
+        .moves()
+        .max_by(|a, b| expected_score(a).total_cmp(&expected_score(b)))
+        .unwrap()
+}
+
+// The highest total score possible against a known sequence of moves
+pub fn optimal_score(rules: &RuleSet, their_moves: &[Move]) -> i32 {
+    let best_score = |their_move: &Move| {
+        score_for_round(rules, (*their_move, best_response(rules, *their_move)))
+    };
+    their_moves.iter().map(best_score).sum()
+}
+
+// The expected total of following a strategy guide against an opponent who plays by
+// `distribution` rather than as the guide predicts, for each reading of its second column
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ColumnScores {
+    pub as_moves: f64,
+    pub as_outcomes: f64,
+}
+
+pub fn expected_scores(
+    rules: &RuleSet,
+    rounds: &[Round],
+    distribution: &MoveDistribution,
+) -> ColumnScores {
+    // Aiming for an outcome means reacting to whatever the opponent plays
+    let expected_for_outcome = |outcome: Outcome| -> f64 {
+        let scores = rules.moves().map(|their_move| {
+            let your_move = move_to_play(rules, their_move, outcome);
+            score_for_round(rules, (their_move, your_move))
+        });
+        scores
+            .zip(&distribution.0)
+            .map(|(score, probability)| score as f64 * probability)
+            .sum()
+    };
+    // Summing floats starts from -0.0, which would show up for a guide without rounds
+    let add = |total: f64, score: f64| total + score;
+    ColumnScores {
+        as_moves: rounds
+            .iter()
+            .map(|round| distribution.expected_score(rules, round.your_move))
+            .fold(0.0, add),
+        as_outcomes: rounds
+            .iter()
+            .map(|round| expected_for_outcome(round.outcome))
+            .fold(0.0, add),
+    }
+}
+
+pub trait Strategy {
+    fn name(&self) -> String;
+    // `history` holds the moves of the rounds played so far, as (yours, theirs)
+    fn next_move(&mut self, rules: &RuleSet, history: &[(Move, Move)]) -> Move;
+    // Called before every match, so that matches don't depend on the ones before
+    fn reset(&mut self) {}
+}
+
+// Plays the same sequence of moves over and over
+pub struct Fixed {
+    name: String,
+    moves: Vec<Move>,
+}
+
+impl Fixed {
+    pub fn new(name: &str, moves: Vec<Move>) -> Result<Self, String> {
+        match moves.is_empty() {
+            true => Err(format!("fixed strategy `{}` has no moves to play", name)),
+            false => Ok(Fixed {
+                name: String::from(name),
+                moves,
+            }),
+        }
+    }
+}
+
+impl Strategy for Fixed {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn next_move(&mut self, _rules: &RuleSet, history: &[(Move, Move)]) -> Move {
+        self.moves[history.len() % self.moves.len()]
+    }
+}
+
+// Plays uniformly random moves
+pub struct Random {
+    seed: u64,
+    rng: Rng,
+}
+
+impl Random {
+    pub fn new(seed: u64) -> Self {
+        Random {
+            seed,
+            rng: Rng::new(seed),
+        }
+    }
+}
+
+impl Strategy for Random {
+    fn name(&self) -> String {
+        format!("random (seed {})", self.seed)
+    }
+
+    fn next_move(&mut self, rules: &RuleSet, _history: &[(Move, Move)]) -> Move {
+        MoveDistribution::uniform(rules).sample(&mut self.rng)
+    }
+
+    fn reset(&mut self) {
+        self.rng = Rng::new(self.seed);
+    }
+}
+
+// Plays the best response to how often the opponent has played each move so far
+pub struct FrequencyCounting;
+
+impl Strategy for FrequencyCounting {
+    fn name(&self) -> String {
+        String::from("frequency counting")
+    }
+
+    fn next_move(&mut self, rules: &RuleSet, history: &[(Move, Move)]) -> Move {
+        let their_moves = history.iter().map(|(_, theirs)| *theirs).collect_vec();
+        best_response_to(rules, &MoveDistribution::of_moves(rules, &their_moves))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Standing {
+    pub name: String,
+    pub score: i32,
+    pub wins: usize,
+    pub draws: usize,
+    pub losses: usize,
+}
+
+impl fmt::Display for Standing {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let record = format!("{}-{}-{}", self.wins, self.draws, self.losses);
+        write!(f, "{:24}{:>8}  {}", self.name, self.score, record)
+    }
+}
+
+// Plays a match of `rounds` rounds between every pair of strategies, returning their standings
+// (in the order of `strategies`). Wins, draws and losses count rounds, not matches.
+pub fn round_robin(
+    rules: &RuleSet,
+    strategies: &mut [Box<dyn Strategy>],
+    rounds: usize,
+) -> Vec<Standing> {
+    let mut standings = strategies
+        .iter()
+        .map(|strategy| Standing {
+            name: strategy.name(),
+            score: 0,
+            wins: 0,
+            draws: 0,
+            losses: 0,
+        })
+        .collect_vec();
+
+    for (a, b) in (0..strategies.len()).tuple_combinations() {
+        let (left, right) = strategies.split_at_mut(b);
+        let (player_a, player_b) = (&mut left[a], &mut right[0]);
+        player_a.reset();
+        player_b.reset();
+        let (mut history_a, mut history_b) = (Vec::new(), Vec::new());
+
+        for _ in 0..rounds {
+            let move_a = player_a.next_move(rules, &history_a);
+            let move_b = player_b.next_move(rules, &history_b);
+            history_a.push((move_a, move_b));
+            history_b.push((move_b, move_a));
+
+            standings[a].score += score_for_round(rules, (move_b, move_a));
+            standings[b].score += score_for_round(rules, (move_a, move_b));
+            match Outcome::of(rules, move_a, move_b) {
+                Outcome::Win => (standings[a].wins += 1, standings[b].losses += 1),
+                Outcome::Draw => (standings[a].draws += 1, standings[b].draws += 1),
+                Outcome::Lose => (standings[a].losses += 1, standings[b].wins += 1),
+            };
+        }
+    }
+    standings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn seeded_rng_is_reproducible() {
+        let mut rng = Rng::new(2022);
+        let first_run = (0..5).map(|_| rng.next_u64()).collect_vec();
+        let mut rng = Rng::new(2022);
+        assert_eq!((0..5).map(|_| rng.next_u64()).collect_vec(), first_run);
+        assert!((0..1000).all(|_| (0.0..1.0).contains(&rng.next_f64())));
+    }
+
+    #[test]
+    fn finds_best_responses() {
+        let rules = RuleSet::rock_paper_scissors();
+        let [rock, paper, scissors] = [0, 1, 2].map(Move);
+        assert_eq!(best_response(&rules, rock), paper);
+        // Against rock twice as often as scissors, paper expects (8 + 8 + 2) / 3 = 6, ahead of
+        // rock's (4 + 4 + 7) / 3 = 5 and scissors' (3 + 3 + 6) / 3 = 4
+        let distribution = MoveDistribution::of_moves(&rules, &[rock, rock, scissors]);
+        assert_eq!(best_response_to(&rules, &distribution), paper);
+        assert!((distribution.expected_score(&rules, paper) - 6.0).abs() < 1e-9);
+        assert_eq!(optimal_score(&rules, &[rock, paper, scissors]), 8 + 9 + 7);
+
+        // Against Spock, winning with lizard (4 + 6) beats winning with paper (2 + 6)
+        let rules = RuleSet::rock_paper_scissors_lizard_spock();
+        let spock = rules.move_named("Spock").unwrap();
+        assert_eq!(rules.name(best_response(&rules, spock)), "Lizard");
+    }
+
+    #[test]
+    fn computes_expected_scores_of_both_readings() {
+        let rules = RuleSet::rock_paper_scissors();
+        let round = Round {
+            their_move: Move(0),
+            your_move: Move(1),
+            outcome: Outcome::Win,
+        };
+        let scores = expected_scores(&rules, &[round], &MoveDistribution::uniform(&rules));
+        // Paper scores 8, 5 or 2 against rock, paper and scissors
+        assert!((scores.as_moves - 5.0).abs() < 1e-9);
+        // Always winning scores 8, 9 or 7
+        assert!((scores.as_outcomes - 8.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn plays_round_robin_tournaments() {
+        let rules = RuleSet::rock_paper_scissors();
+        let mut strategies: Vec<Box<dyn Strategy>> = vec![
+            Box::new(Fixed::new("rock", vec![Move(0)]).unwrap()),
+            Box::new(FrequencyCounting),
+            Box::new(Random::new(7)),
+        ];
+        let standings = round_robin(&rules, &mut strategies, 100);
+        for standing in &standings {
+            assert_eq!(standing.wins + standing.draws + standing.losses, 200);
+        }
+        // Counting frequencies sees through always playing rock after the first round
+        assert!(standings[1].wins >= 99);
+        assert_eq!(standings, round_robin(&rules, &mut strategies, 100));
+        assert!(Fixed::new("nothing", Vec::new()).is_err());
+    }
+}
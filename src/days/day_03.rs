@@ -0,0 +1,550 @@
+// https://adventofcode.com/2022/day/3
+
+use crate::solution::{Answer, InputReport, ReportOptions, Solution};
+use crate::utils::{Error, ParseError};
+use itertools::Itertools;
+use std::fmt;
+use std::iter;
+use std::num::NonZeroUsize;
+
+pub mod reorganisation;
+
+use reorganisation::{plan_group, plan_rucksack, BadgePlan, Compartment, ItemMove};
+
+pub fn priority(chr: char) -> u32 {
+    let val_of_chr = chr as u32;
+    if chr.is_lowercase() {
+        1 + val_of_chr - 'a' as u32
+    } else {
+        27 + val_of_chr - 'A' as u32
+    }
+}
+
+pub fn item_with_priority(priority: u32) -> char {
+    match priority {
+        1..=26 => char::from(b'a' + (priority - 1) as u8),
+        _ => char::from(b'A' + (priority - 27) as u8),
+    }
+}
+
+// A set of items (the letters a-z and A-Z), with an item's priority as its bit
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ItemSet(u64);
+
+impl ItemSet {
+    pub const EMPTY: ItemSet = ItemSet(0);
+
+    // `items` must only hold letters
+    pub fn of(items: &str) -> Self {
+        let bits = items
+            .bytes()
+            .fold(0, |bits, item| bits | 1 << priority(item as char));
+        ItemSet(bits)
+    }
+
+    pub fn insert(&mut self, item: char) {
+        self.0 |= 1 << priority(item);
+    }
+
+    pub fn contains(&self, item: char) -> bool {
+        self.0 & 1 << priority(item) != 0
+    }
+
+    pub fn intersection(self, other: ItemSet) -> ItemSet {
+        ItemSet(self.0 & other.0)
+    }
+
+    pub fn union(self, other: ItemSet) -> ItemSet {
+        ItemSet(self.0 | other.0)
+    }
+
+    pub fn difference(self, other: ItemSet) -> ItemSet {
+        ItemSet(self.0 & !other.0)
+    }
+
+    pub fn len(&self) -> u32 {
+        self.0.count_ones()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    // Items in order of priority
+    pub fn items(self) -> impl Iterator<Item = char> {
+        let mut bits = self.0;
+        iter::from_fn(move || {
+            let lowest_priority = bits.trailing_zeros();
+            (bits != 0).then(|| {
+                bits &= bits - 1;
+                item_with_priority(lowest_priority)
+            })
+        })
+    }
+
+    pub fn priority_sum(&self) -> u32 {
+        self.items().map(priority).sum()
+    }
+}
+
+pub fn items_in_both_parts(line: &str) -> ItemSet {
+    let (first, second) = line.split_at(line.len() / 2);
+    ItemSet::of(first).intersection(ItemSet::of(second))
+}
+
+pub fn part_one(lines: &[String]) -> u32 {
+    lines
+        .iter()
+        .map(|line| items_in_both_parts(line).priority_sum())
+        .sum()
+}
+
+// Elves are split into groups of this many in the puzzle
+pub const GROUP_SIZE: NonZeroUsize = NonZeroUsize::new(3).unwrap();
+
+// The items carried by every elf in the group, which should be just their badge
+pub fn badge_candidates(group: &[String]) -> ItemSet {
+    group
+        .iter()
+        .map(|line| ItemSet::of(line))
+        .reduce(ItemSet::intersection)
+        .unwrap_or(ItemSet::EMPTY)
+}
+
+// Groups without exactly one badge, including a last group with too few elves, add nothing
+// to the sum. The solver doesn't parse such inputs, which `BadgeReport` lists instead.
+pub fn part_two(lines: &[String], group_size: NonZeroUsize) -> u32 {
+    let groups = lines.chunks_exact(group_size.get());
+    let badges = groups
+        .map(badge_candidates)
+        .filter(|candidates| candidates.len() == 1);
+    badges.map(|badge| badge.priority_sum()).sum()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GroupProblem {
+    NoBadge,
+    SeveralBadges(ItemSet),
+    // The last group has fewer elves than the others when the rucksacks don't split evenly
+    Incomplete(usize),
+}
+
+impl fmt::Display for GroupProblem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GroupProblem::NoBadge => write!(f, "no item in common"),
+            GroupProblem::SeveralBadges(candidates) => {
+                let items = candidates.items().collect::<String>();
+                write!(f, "{} items in common ({})", candidates.len(), items)
+            }
+            GroupProblem::Incomplete(elves) => write!(f, "only {} elves", elves),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BadGroup {
+    // Position of the group, and of the line its first rucksack is on, from 0
+    pub idx: usize,
+    pub first_line_idx: usize,
+    pub problem: GroupProblem,
+}
+
+pub fn bad_groups(lines: &[String], group_size: NonZeroUsize) -> Vec<BadGroup> {
+    let problem_of = |group: &[String]| {
+        let candidates = badge_candidates(group);
+        match candidates.len() {
+            _ if group.len() < group_size.get() => Some(GroupProblem::Incomplete(group.len())),
+            0 => Some(GroupProblem::NoBadge),
+            1 => None,
+            _ => Some(GroupProblem::SeveralBadges(candidates)),
+        }
+    };
+    let groups = lines.chunks(group_size.get()).enumerate();
+    groups
+        .filter_map(|(group_idx, group)| {
+            problem_of(group).map(|problem| BadGroup {
+                idx: group_idx,
+                first_line_idx: group_idx * group_size.get(),
+                problem,
+            })
+        })
+        .collect()
+}
+
+// The groups of elves without exactly one badge, and the rucksacks that can't be split evenly
+// between their compartments
+pub struct BadgeReport {
+    pub group_size: NonZeroUsize,
+    pub rucksacks: usize,
+    pub bad_groups: Vec<BadGroup>,
+    // Indices of the lines holding an odd number of items, from 0
+    pub odd_rucksacks: Vec<usize>,
+}
+
+impl BadgeReport {
+    pub fn new(lines: &[String], group_size: NonZeroUsize) -> Self {
+        let odd_rucksacks = lines
+            .iter()
+            .positions(|line| !line.len().is_multiple_of(2))
+            .collect();
+        BadgeReport {
+            group_size,
+            rucksacks: lines.len(),
+            bad_groups: bad_groups(lines, group_size),
+            odd_rucksacks,
+        }
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.bad_groups.is_empty() && self.odd_rucksacks.is_empty()
+    }
+}
+
+impl InputReport for BadgeReport {
+    type Solver = Solver;
+    const NAME: &'static str = "badges";
+    const ABOUT: &'static str = "Groups without exactly one badge, and unevenly packed rucksacks";
+    const OPTIONS: &'static [&'static str] = &["group-size"];
+
+    fn parse(lines: impl Iterator<Item = String>) -> Result<Vec<String>, Error> {
+        parse_rucksacks(lines, check_items)
+    }
+
+    fn of(lines: &Vec<String>) -> Self {
+        BadgeReport::new(lines, GROUP_SIZE)
+    }
+
+    fn with_options(lines: &Vec<String>, options: &ReportOptions) -> Result<Self, Error> {
+        let group_size = options.get("group-size")?.unwrap_or(GROUP_SIZE);
+        Ok(BadgeReport::new(lines, group_size))
+    }
+}
+
+impl fmt::Display for BadgeReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{} rucksacks in {} groups of {}",
+            self.rucksacks,
+            self.rucksacks.div_ceil(self.group_size.get()),
+            self.group_size
+        )?;
+        for group in &self.bad_groups {
+            let line = group.first_line_idx + 1;
+            writeln!(
+                f,
+                "Group {} (from line {}): {}",
+                group.idx + 1,
+                line,
+                group.problem
+            )?;
+        }
+        for line_idx in &self.odd_rucksacks {
+            writeln!(f, "Line {}: odd number of items", line_idx + 1)?;
+        }
+        if self.is_valid() {
+            writeln!(f, "Every group has one badge")?;
+        }
+        Ok(())
+    }
+}
+
+// How to repack each rucksack so that no item is in both compartments, and which rucksacks
+// have to change for each group to agree on a single badge
+pub struct ReorganisationReport {
+    pub group_size: NonZeroUsize,
+    // Indexed by line, and by group
+    pub moves: Vec<Vec<ItemMove>>,
+    pub badge_plans: Vec<Option<BadgePlan>>,
+}
+
+impl ReorganisationReport {
+    pub fn new(lines: &[String], group_size: NonZeroUsize) -> Self {
+        ReorganisationReport {
+            group_size,
+            moves: lines.iter().map(|line| plan_rucksack(line)).collect(),
+            badge_plans: lines.chunks(group_size.get()).map(plan_group).collect(),
+        }
+    }
+
+    pub fn total_moves(&self) -> usize {
+        let counts = self.moves.iter().flatten().map(|item_move| item_move.count);
+        counts.sum()
+    }
+}
+
+impl InputReport for ReorganisationReport {
+    type Solver = Solver;
+    const NAME: &'static str = "reorganise";
+    const ABOUT: &'static str = "The fewest item moves to fix each rucksack, and badge changes";
+    const OPTIONS: &'static [&'static str] = &["group-size"];
+
+    fn parse(lines: impl Iterator<Item = String>) -> Result<Vec<String>, Error> {
+        parse_rucksacks(lines, check_items)
+    }
+
+    fn of(lines: &Vec<String>) -> Self {
+        ReorganisationReport::new(lines, GROUP_SIZE)
+    }
+
+    fn with_options(lines: &Vec<String>, options: &ReportOptions) -> Result<Self, Error> {
+        let group_size = options.get("group-size")?.unwrap_or(GROUP_SIZE);
+        Ok(ReorganisationReport::new(lines, group_size))
+    }
+}
+
+impl fmt::Display for ReorganisationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Rucksacks ({} items to move)", self.total_moves())?;
+        for (line_idx, moves) in self.moves.iter().enumerate() {
+            if moves.is_empty() {
+                continue;
+            }
+            let moves = moves.iter().map(|item_move| {
+                let direction = match item_move.from {
+                    Compartment::First => "1 -> 2",
+                    Compartment::Second => "2 -> 1",
+                };
+                format!("{} x{} ({})", item_move.item, item_move.count, direction)
+            });
+            writeln!(f, "{:>6}  {}", line_idx + 1, moves.format(", "))?;
+        }
+
+        writeln!(f, "\nGroups")?;
+        let plans = self.badge_plans.iter().enumerate();
+        let plans =
+            plans.filter_map(|(group_idx, plan)| plan.as_ref().map(|plan| (group_idx, plan)));
+        let mut all_agree = true;
+        for (group_idx, plan) in plans.filter(|(_, plan)| !plan.changes.is_empty()) {
+            all_agree = false;
+            let changes = plan.changes.iter().map(|change| {
+                let line = group_idx * self.group_size.get() + change.idx + 1;
+                let removed = change.remove.items().collect::<String>();
+                match (change.add_badge, removed.is_empty()) {
+                    (true, true) => format!("line {} adds {}", line, plan.badge),
+                    (true, false) => {
+                        format!("line {} adds {}, removes {}", line, plan.badge, removed)
+                    }
+                    (false, _) => format!("line {} removes {}", line, removed),
+                }
+            });
+            let group = group_idx + 1;
+            writeln!(
+                f,
+                "{:>6}  badge {}: {}",
+                group,
+                plan.badge,
+                changes.format("; ")
+            )?;
+        }
+        if all_agree {
+            writeln!(f, "Every group already has one badge")?;
+        }
+        Ok(())
+    }
+}
+
+// Rucksacks hold letters only
+fn check_items(line: &str) -> Result<(), ParseError> {
+    match line.find(|chr: char| !chr.is_ascii_alphabetic()) {
+        Some(idx) => {
+            let item = line[idx..].chars().next().unwrap();
+            Err(ParseError::new(idx + 1, format!("invalid item `{}`", item)))
+        }
+        None => Ok(()),
+    }
+}
+
+// The parts also need the items split evenly between both compartments. The reports take
+// rucksacks which aren't, to point them out.
+fn check_rucksack(line: &str) -> Result<(), ParseError> {
+    check_items(line)?;
+    match line.len() % 2 {
+        0 => Ok(()),
+        _ => Err(ParseError::new(
+            1,
+            "expected as many items in both compartments",
+        )),
+    }
+}
+
+fn parse_rucksacks(
+    lines: impl Iterator<Item = String>,
+    check: fn(&str) -> Result<(), ParseError>,
+) -> Result<Vec<String>, Error> {
+    let check_line = |(line_idx, line): (usize, String)| match check(&line) {
+        Ok(()) => Ok(line),
+        Err(err) => Err(err.on_line(Solver::DAY, line_idx)),
+    };
+    lines.enumerate().map(check_line).collect()
+}
+
+// Answers part two for groups of `N` elves, which can't be 0
+pub struct GroupSolver<const N: usize>;
+
+pub type Solver = GroupSolver<{ GROUP_SIZE.get() }>;
+
+impl<const N: usize> GroupSolver<N> {
+    const GROUP_SIZE: NonZeroUsize = NonZeroUsize::new(N).expect("groups need an elf");
+}
+
+impl<const N: usize> Solution for GroupSolver<N> {
+    const DAY: u8 = 3;
+    type Input = Vec<String>;
+
+    // Part two needs every group to have a single badge
+    fn parse(lines: impl Iterator<Item = String>) -> Result<Self::Input, Error> {
+        let lines = parse_rucksacks(lines, check_rucksack)?;
+        match bad_groups(&lines, Self::GROUP_SIZE).first() {
+            Some(group) => {
+                let msg = format!("group {} has {}", group.idx + 1, group.problem);
+                let err = ParseError::new(1, msg);
+                Err(err.on_line(Self::DAY, group.first_line_idx))
+            }
+            None => Ok(lines),
+        }
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        Answer::from(part_one(input))
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        Answer::from(part_two(input, Self::GROUP_SIZE))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::{render_report, solve};
+    use crate::utils::InputSource;
+    use std::path::PathBuf;
+
+    #[test]
+    fn solves_example() {
+        let example = InputSource::Path(PathBuf::from("inputs/examples"));
+        let answers = solve::<Solver>(&example).unwrap().answers;
+        assert_eq!(answers, [Answer::from(157), Answer::from(70)]);
+    }
+
+    #[test]
+    fn solves_input() {
+        let answers = solve::<Solver>(&InputSource::Default).unwrap().answers;
+        assert_eq!(answers, [Answer::from(8394), Answer::from(2413)]);
+    }
+
+    #[test]
+    fn intersects_item_sets() {
+        let first = ItemSet::of("vJrwpWtwJgWr");
+        let second = ItemSet::of("hcsFMMfFFhFp");
+        assert_eq!(
+            first.intersection(second).items().collect::<Vec<_>>(),
+            ['p']
+        );
+        assert_eq!(first.len(), 8);
+        assert_eq!(first.union(second).len(), 14);
+        assert!(first.contains('W') && !first.contains('h'));
+        assert_eq!(ItemSet::of("azAZ").priority_sum(), 1 + 26 + 27 + 52);
+    }
+
+    #[test]
+    fn reports_invalid_rucksacks() {
+        let lines = ["abAB", "ab1B"].map(String::from).into_iter();
+        assert!(matches!(
+            Solver::parse(lines),
+            Err(Error::Parse {
+                line: 2,
+                column: 3,
+                ..
+            })
+        ));
+        assert!(check_rucksack("abc").is_err());
+        // The reports take unevenly packed rucksacks, to list them
+        let lines = ["abc"].map(String::from).into_iter();
+        assert_eq!(BadgeReport::parse(lines).unwrap(), ["abc"]);
+    }
+
+    #[test]
+    fn validates_badges() {
+        let example = InputSource::Path(PathBuf::from("inputs/examples/day-03.txt"));
+        let lines = Solver::parse(example.lines(3).unwrap()).unwrap();
+        assert!(BadgeReport::new(&lines, GROUP_SIZE).is_valid());
+        // Pairs of elves have several items in common, so none of them has a badge
+        let [pairs, triples] = [2, 3].map(|size| NonZeroUsize::new(size).unwrap());
+        assert_eq!(part_two(&lines, pairs), 0);
+        assert_eq!(bad_groups(&lines, pairs).len(), 3);
+        assert!(matches!(
+            solve::<GroupSolver<2>>(&example),
+            Err(Error::Parse { line: 1, .. })
+        ));
+
+        // A last group with too few elves has no badge, even with a single item in common
+        let lines = ["aB", "aC", "aD"].map(String::from);
+        assert_eq!(part_two(&lines, pairs), priority('a'));
+        let problems = bad_groups(&lines, pairs)
+            .into_iter()
+            .map(|group| group.problem);
+        assert_eq!(problems.collect_vec(), [GroupProblem::Incomplete(1)]);
+        let err = GroupSolver::<2>::parse(lines.into_iter()).unwrap_err();
+        assert!(err.to_string().ends_with("group 2 has only 1 elves"));
+
+        let lines = ["abCd", "bCde", "ab", "xyz"].map(String::from);
+        let report = BadgeReport::new(&lines, pairs);
+        let problems = report.bad_groups.iter().map(|group| &group.problem);
+        assert_eq!(
+            problems.collect_vec(),
+            [
+                &GroupProblem::SeveralBadges(ItemSet::of("bCd")),
+                &GroupProblem::NoBadge
+            ]
+        );
+        assert_eq!(report.odd_rucksacks, [3]);
+        let report = BadgeReport::new(&lines, triples);
+        assert_eq!(report.bad_groups[0].problem, GroupProblem::Incomplete(1));
+        assert_eq!(report.bad_groups[0].first_line_idx, 3);
+    }
+
+    #[test]
+    fn takes_the_group_size_as_a_report_option() {
+        let example = InputSource::Path(PathBuf::from("inputs/examples/day-03.txt"));
+        let render = |options: &[(&str, &str)]| {
+            let options = options
+                .iter()
+                .map(|(name, value)| (name.to_string(), value.to_string()));
+            render_report::<BadgeReport>(&example, &ReportOptions(options.collect()))
+        };
+        assert!(render(&[])
+            .unwrap()
+            .ends_with("Every group has one badge\n"));
+        assert!(render(&[("group-size", "2")])
+            .unwrap()
+            .contains("in 3 groups of 2"));
+        assert!(matches!(
+            render(&[("group-size", "0")]),
+            Err(Error::Usage(_))
+        ));
+        assert!(matches!(render(&[("groups", "2")]), Err(Error::Usage(_))));
+    }
+
+    #[test]
+    fn plans_reorganisation() {
+        let example = InputSource::Path(PathBuf::from("inputs/examples/day-03.txt"));
+        let lines = Solver::parse(example.lines(3).unwrap()).unwrap();
+        let report = ReorganisationReport::new(&lines, GROUP_SIZE);
+        // Every rucksack has one item type in both compartments, and it's the one part one finds
+        let duplicates = report
+            .moves
+            .iter()
+            .map(|moves| moves[0].item)
+            .collect::<String>();
+        assert_eq!(duplicates, "pLPvts");
+        assert!(report.moves.iter().all(|moves| moves.len() == 1));
+        assert!(report
+            .badge_plans
+            .iter()
+            .flatten()
+            .all(|plan| plan.changes.is_empty()));
+    }
+}
@@ -0,0 +1,152 @@
+// Plans for fixing the packing mistakes: moving items so that no item is in both compartments
+// of a rucksack, and changing rucksacks so that each group has a single badge.
+
+use super::{items_in_both_parts, ItemSet};
+use itertools::Itertools;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Compartment {
+    First,
+    Second,
+}
+
+// Moves `count` copies of `item` out of the `from` compartment into the other one
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ItemMove {
+    pub item: char,
+    pub count: usize,
+    pub from: Compartment,
+}
+
+// The fewest item moves leaving no item in both compartments. Each duplicated item is gathered
+// in whichever compartment holds more copies of it (the first on ties), so the compartments
+// can end up holding different numbers of items.
+pub fn plan_rucksack(line: &str) -> Vec<ItemMove> {
+    let (first, second) = line.split_at(line.len() / 2);
+    let count = |compartment: &str, item: char| compartment.matches(item).count();
+    items_in_both_parts(line)
+        .items()
+        .map(|item| {
+            let (in_first, in_second) = (count(first, item), count(second, item));
+            match in_first < in_second {
+                true => ItemMove {
+                    item,
+                    count: in_first,
+                    from: Compartment::First,
+                },
+                false => ItemMove {
+                    item,
+                    count: in_second,
+                    from: Compartment::Second,
+                },
+            }
+        })
+        .collect()
+}
+
+// What has to change in one rucksack of a group: taking the badge in when it's missing, and
+// taking out items that every elf would otherwise still have in common
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RucksackChange {
+    // Position of the rucksack in its group, from 0
+    pub idx: usize,
+    pub add_badge: bool,
+    pub remove: ItemSet,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BadgePlan {
+    pub badge: char,
+    pub changes: Vec<RucksackChange>,
+}
+
+// The changes to the group when `badge` is to be its only common item. Rucksacks without the
+// badge have to take it in, and any other common items are taken out of one rucksack, which
+// is one already changing when there is one.
+fn plan_for_badge(rucksacks: &[ItemSet], badge: char) -> BadgePlan {
+    let mut badge_set = ItemSet::EMPTY;
+    badge_set.insert(badge);
+    let common = rucksacks
+        .iter()
+        .map(|rucksack| rucksack.union(badge_set))
+        .reduce(ItemSet::intersection)
+        .unwrap_or(ItemSet::EMPTY);
+    let extra = common.difference(badge_set);
+
+    let mut changes = rucksacks
+        .iter()
+        .positions(|rucksack| !rucksack.contains(badge))
+        .map(|idx| RucksackChange {
+            idx,
+            add_badge: true,
+            remove: ItemSet::EMPTY,
+        })
+        .collect_vec();
+    if !extra.is_empty() {
+        match changes.first_mut() {
+            Some(change) => change.remove = extra,
+            None => changes.push(RucksackChange {
+                idx: 0,
+                add_badge: false,
+                remove: extra,
+            }),
+        }
+    }
+    BadgePlan { badge, changes }
+}
+
+// The badge needing the fewest rucksacks to change for the group to agree on it, with the
+// lowest priority badge on ties. A group with a single badge needs no changes.
+pub fn plan_group(group: &[String]) -> Option<BadgePlan> {
+    let rucksacks = group.iter().map(|line| ItemSet::of(line)).collect_vec();
+    let all_items = rucksacks
+        .iter()
+        .fold(ItemSet::EMPTY, |all, rucksack| all.union(*rucksack));
+    all_items
+        .items()
+        .map(|badge| plan_for_badge(&rucksacks, badge))
+        .min_by_key(|plan| plan.changes.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plans_item_moves() {
+        assert_eq!(
+            plan_rucksack("aabBcb"),
+            [ItemMove {
+                item: 'b',
+                count: 1,
+                from: Compartment::Second,
+            }]
+        );
+        assert_eq!(plan_rucksack("aAbB").len(), 0);
+        let moves = plan_rucksack("xxyyxy");
+        assert_eq!(moves[0].from, Compartment::Second);
+        assert_eq!(moves[1].from, Compartment::First);
+    }
+
+    #[test]
+    fn plans_badges() {
+        let group = ["abc", "bcd", "bce"].map(String::from);
+        let plan = plan_group(&group).unwrap();
+        assert_eq!(plan.badge, 'b');
+        let remove_c = RucksackChange {
+            idx: 0,
+            add_badge: false,
+            remove: ItemSet::of("c"),
+        };
+        assert_eq!(plan.changes, [remove_c]);
+
+        let group = ["ab", "ac", "de"].map(String::from);
+        let plan = plan_group(&group).unwrap();
+        assert_eq!((plan.badge, plan.changes.len()), ('a', 1));
+        assert!(plan.changes[0].add_badge);
+        assert!(plan_group(&["ab", "bc"].map(String::from))
+            .unwrap()
+            .changes
+            .is_empty());
+    }
+}
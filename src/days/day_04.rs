@@ -0,0 +1,122 @@
+// https://adventofcode.com/2022/day/4
+
+use crate::interval::{Interval, IntervalSet};
+use crate::solution::{Answer, Solution};
+use crate::utils::{parse_each_line, parse_number, split_once, Error, ParseError};
+
+// The sections assigned to an elf
+pub type Range = Interval;
+
+pub fn parse_range(range_str: &str) -> Result<Range, ParseError> {
+    let (start_str, end_str) = split_once(range_str, "-")?;
+    let (start, end) = (
+        parse_number::<i64>(start_str, 1)?,
+        parse_number::<i64>(end_str, start_str.len() + 2)?,
+    );
+    match start <= end {
+        true => Ok(Interval::new(start, end)),
+        false => Err(ParseError::new(1, "range ends before it starts")),
+    }
+}
+
+pub fn parse_line(line: &str) -> Result<(Range, Range), ParseError> {
+    let (range_str_a, range_str_b) = split_once(line, ",")?;
+    Ok((
+        parse_range(range_str_a)?,
+        parse_range(range_str_b).map_err(|err| err.offset(range_str_a.len() + 1))?,
+    ))
+}
+
+pub fn is_either_subset(range_a: &Range, range_b: &Range) -> bool {
+    range_a.contains_interval(range_b) || range_b.contains_interval(range_a)
+}
+
+pub fn is_overlapping_range(range: &Range, other_range: &Range) -> bool {
+    range.overlaps(other_range)
+}
+
+// The sections assigned to any elf
+pub fn covered_sections(range_pairs: &[(Range, Range)]) -> IntervalSet {
+    let ranges = range_pairs.iter().flat_map(|(a, b)| [*a, *b]);
+    ranges.collect()
+}
+
+// The sections between the first and last assigned ones that nobody was assigned
+pub fn uncovered_sections(range_pairs: &[(Range, Range)]) -> IntervalSet {
+    let covered = covered_sections(range_pairs);
+    match (covered.intervals().first(), covered.intervals().last()) {
+        (Some(first), Some(last)) => covered.gaps_within(Interval::new(first.start(), last.end())),
+        _ => IntervalSet::new(),
+    }
+}
+
+pub fn count_filtered_ranges(
+    range_pairs: &[(Range, Range)],
+    filter_fn: fn(&Range, &Range) -> bool,
+) -> usize {
+    let subset_pairs = range_pairs.iter().filter(|(a, b)| filter_fn(a, b));
+    subset_pairs.count()
+}
+
+pub fn part_one(range_pairs: &[(Range, Range)]) -> usize {
+    count_filtered_ranges(range_pairs, is_either_subset)
+}
+
+pub fn part_two(range_pairs: &[(Range, Range)]) -> usize {
+    count_filtered_ranges(range_pairs, is_overlapping_range)
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const DAY: u8 = 4;
+    type Input = Vec<(Range, Range)>;
+
+    fn parse(lines: impl Iterator<Item = String>) -> Result<Self::Input, Error> {
+        parse_each_line(Self::DAY, lines.enumerate(), parse_line)
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        Answer::from(part_one(input))
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        Answer::from(part_two(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::solve;
+    use crate::utils::InputSource;
+    use std::path::PathBuf;
+
+    #[test]
+    fn solves_example() {
+        let example = InputSource::Path(PathBuf::from("inputs/examples"));
+        let answers = solve::<Solver>(&example).unwrap().answers;
+        assert_eq!(answers, [Answer::from(2), Answer::from(4)]);
+    }
+
+    #[test]
+    fn solves_input() {
+        let answers = solve::<Solver>(&InputSource::Default).unwrap().answers;
+        assert_eq!(answers, [Answer::from(550), Answer::from(931)]);
+    }
+
+    #[test]
+    fn finds_uncovered_sections() {
+        let example = InputSource::Path(PathBuf::from("inputs/examples/day-04.txt"));
+        let range_pairs = Solver::parse(example.lines(4).unwrap()).unwrap();
+        assert_eq!(covered_sections(&range_pairs).to_string(), "{2-9}");
+        assert!(uncovered_sections(&range_pairs).is_empty());
+
+        let range_pairs = [
+            parse_line("1-2,8-9").unwrap(),
+            parse_line("4-4,3-3").unwrap(),
+        ];
+        assert_eq!(uncovered_sections(&range_pairs).to_string(), "{5-7}");
+        assert!(parse_line("2-4,6-5").is_err());
+    }
+}
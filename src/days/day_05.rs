@@ -0,0 +1,236 @@
+// https://adventofcode.com/2022/day/5
+
+use itertools::Itertools;
+use std::iter::Iterator;
+
+use crate::solution::{Answer, Solution};
+use crate::utils::{parse_each_line, parse_number, split_with_columns, Error, ParseError};
+use std::char;
+use std::collections::HashMap;
+use std::str::FromStr;
+
+pub struct Move {
+    pub count: i8,
+    pub from: i8,
+    pub to: i8,
+}
+
+impl FromStr for Move {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let mut parts = split_with_columns(line, ' ')
+            .filter(|(_, part)| part.chars().all(|c| c.is_ascii_digit()))
+            .map(|(column, part)| parse_number::<i8>(part, column));
+        let mut next_part = || {
+            parts.next().unwrap_or_else(|| {
+                let msg = "expected `move <count> from <stack> to <stack>`";
+                Err(ParseError::new(line.len() + 1, msg))
+            })
+        };
+
+        Ok(Move {
+            count: next_part()?,
+            from: next_part()?,
+            to: next_part()?,
+        })
+    }
+}
+
+pub type Stacks = HashMap<i8, Vec<char>>;
+
+pub struct Procedure {
+    pub stacks: Stacks,
+    pub moves: Vec<Move>,
+}
+
+pub fn parse_moves(
+    numbered_lines: impl Iterator<Item = (usize, String)>,
+) -> Result<Vec<Move>, Error> {
+    let numbered_lines = numbered_lines.filter(|(_, line)| !line.is_empty());
+    parse_each_line(Solver::DAY, numbered_lines, Move::from_str)
+}
+
+// Each stack entry takes 3 characters. And one space between each stack entry.
+// 3n + (n - 1) = str_size where n is the number of entries.
+fn parse_stack_items(line: &str) -> Vec<char> {
+    let chars = line.chars().collect_vec();
+    (1..chars.len())
+        .step_by(4) // There's one item character at every 4th char
+        .map(|idx| *chars.get(idx).unwrap())
+        .collect_vec()
+}
+
+// Consumes the lines of the stack definition, leaving the moves in `lines`
+pub fn parse_stacks(lines: &mut impl Iterator<Item = String>) -> Stacks {
+    let stacks: &mut Stacks = &mut HashMap::new();
+
+    for line in lines {
+        if line.is_empty() {
+            // Reached the end of the stack definition; break
+            break;
+        }
+
+        for (i, val) in parse_stack_items(&line).iter().enumerate() {
+            let stack_idx: i8 = 1 + i as i8; // Change from 0-indexed to 1-indexed
+            stacks.entry(stack_idx).or_default().push(*val);
+        }
+    }
+
+    for stack in stacks.values_mut() {
+        stack.pop(); // Remove the stack indexes
+        stack.retain(|x| *x != ' '); // Filter empty chars
+        stack.reverse(); // Reverse to mimic reading bottom up
+    }
+
+    stacks.clone()
+}
+
+// Checks that every move is between stacks that exist and takes no more crates than there are,
+// returning the position of the first move that doesn't along with why
+pub fn check_moves(stacks: &Stacks, moves: &[Move]) -> Result<(), (usize, String)> {
+    let mut heights: HashMap<i8, usize> = stacks
+        .iter()
+        .map(|(stack_idx, stack)| (*stack_idx, stack.len()))
+        .collect();
+    for (move_idx, item_move) in moves.iter().enumerate() {
+        for stack_idx in [item_move.from, item_move.to] {
+            if !heights.contains_key(&stack_idx) {
+                return Err((move_idx, format!("no stack {}", stack_idx)));
+            }
+        }
+        let (count, from_height) = (item_move.count as usize, heights[&item_move.from]);
+        if count > from_height {
+            let msg = format!(
+                "can't move {} crates from stack {}, which holds {}",
+                count, item_move.from, from_height
+            );
+            return Err((move_idx, msg));
+        }
+        *heights.get_mut(&item_move.from).unwrap() -= count;
+        *heights.get_mut(&item_move.to).unwrap() += count;
+    }
+    Ok(())
+}
+
+fn repr_for_stacks(stacks: &Stacks) -> String {
+    let sorted_stack_vals = stacks.keys().sorted().map(|key| stacks.get(key).unwrap());
+    String::from(
+        &sorted_stack_vals
+            .map(|stack| stack.last().unwrap_or(&' '))
+            .join(""),
+    )
+}
+
+pub fn part_one(procedure: &Procedure) -> String {
+    let mut stacks = procedure.stacks.clone();
+    for item_move in &procedure.moves {
+        for _ in 0..item_move.count {
+            let from_stack = stacks.get_mut(&item_move.from).unwrap();
+            let item_to_insert = from_stack.pop().unwrap();
+            stacks.get_mut(&item_move.to).unwrap().push(item_to_insert);
+        }
+    }
+    repr_for_stacks(&stacks)
+}
+
+pub fn part_two(procedure: &Procedure) -> String {
+    let mut stacks = procedure.stacks.clone();
+    for item_move in &procedure.moves {
+        let from_stack = stacks.get_mut(&item_move.from).unwrap();
+        let mut items_to_insert: Vec<char> = Vec::new();
+
+        for _ in 0..item_move.count {
+            items_to_insert.push(from_stack.pop().unwrap());
+        }
+
+        items_to_insert.reverse();
+        stacks
+            .get_mut(&item_move.to)
+            .unwrap()
+            .append(&mut items_to_insert);
+    }
+    repr_for_stacks(&stacks)
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const DAY: u8 = 5;
+    type Input = Procedure;
+
+    fn parse(lines: impl Iterator<Item = String>) -> Result<Self::Input, Error> {
+        let mut numbered_lines = lines.enumerate();
+        let stacks = parse_stacks(&mut numbered_lines.by_ref().map(|(_, line)| line));
+        let move_lines = numbered_lines
+            .filter(|(_, line)| !line.is_empty())
+            .collect_vec();
+        let line_idxs = move_lines
+            .iter()
+            .map(|(line_idx, _)| *line_idx)
+            .collect_vec();
+        let moves = parse_moves(move_lines.into_iter())?;
+
+        // The parts rely on every move being possible
+        check_moves(&stacks, &moves).map_err(|(move_idx, msg)| {
+            ParseError::new(1, msg).on_line(Self::DAY, line_idxs[move_idx])
+        })?;
+        Ok(Procedure { stacks, moves })
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        Answer::from(part_one(input))
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        Answer::from(part_two(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::solve;
+    use crate::utils::InputSource;
+    use std::path::PathBuf;
+
+    #[test]
+    fn solves_example() {
+        let example = InputSource::Path(PathBuf::from("inputs/examples"));
+        let answers = solve::<Solver>(&example).unwrap().answers;
+        assert_eq!(answers, [Answer::from("CMZ"), Answer::from("MCD")]);
+    }
+
+    #[test]
+    fn solves_input() {
+        let answers = solve::<Solver>(&InputSource::Default).unwrap().answers;
+        assert_eq!(
+            answers,
+            [Answer::from("BSDMQFLSP"), Answer::from("PGSQBFLDP")]
+        );
+    }
+
+    #[test]
+    fn reports_impossible_moves() {
+        let parse = |moves: &str| {
+            let input = format!("[A]    \n[B] [C]\n 1   2 \n\n{}", moves);
+            Solver::parse(input.lines().map(String::from))
+        };
+        assert!(parse("move 2 from 1 to 2\nmove 3 from 2 to 1").is_ok());
+        let error_line = |moves| match parse(moves) {
+            Err(Error::Parse { line, message, .. }) => (line, message),
+            _ => panic!("expected a parse error"),
+        };
+        assert_eq!(
+            error_line("move 1 from 1 to 2\nmove 1 from 3 to 1"),
+            (6, String::from("no stack 3"))
+        );
+        assert_eq!(
+            error_line("move 2 from 2 to 1"),
+            (
+                5,
+                String::from("can't move 2 crates from stack 2, which holds 1")
+            )
+        );
+    }
+}
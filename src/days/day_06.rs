@@ -0,0 +1,95 @@
+// https://adventofcode.com/2022/day/6
+
+use itertools::Itertools;
+use std::iter::Iterator;
+
+use crate::solution::{Answer, Solution};
+use crate::utils::{Error, ParseError};
+use std::char;
+use std::collections::HashSet;
+
+// The number of characters read once the last `window_size` of them are all different
+pub fn get_marker_idx(chars: &[char], window_size: usize) -> Option<usize> {
+    let sliding_windows = chars.windows(window_size).map(|window| window.to_vec());
+
+    for (idx, window) in sliding_windows.enumerate() {
+        if HashSet::<char>::from_iter(window).len() == window_size {
+            return Some(idx + window_size);
+        }
+    }
+    None
+}
+
+pub const PACKET_MARKER_SIZE: usize = 4;
+pub const MESSAGE_MARKER_SIZE: usize = 14;
+
+// Both markers are checked for when parsing
+pub fn part_one(datastream: &[char]) -> usize {
+    get_marker_idx(datastream, PACKET_MARKER_SIZE).unwrap()
+}
+
+pub fn part_two(datastream: &[char]) -> usize {
+    get_marker_idx(datastream, MESSAGE_MARKER_SIZE).unwrap()
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const DAY: u8 = 6;
+    type Input = Vec<char>;
+
+    fn parse(mut lines: impl Iterator<Item = String>) -> Result<Self::Input, Error> {
+        let datastream = lines
+            .next()
+            .ok_or_else(|| ParseError::new(1, "expected a datastream").on_line(Self::DAY, 0))?;
+        let datastream = datastream.chars().collect_vec();
+        // A message marker also holds a packet marker, as all of its characters are different
+        match get_marker_idx(&datastream, MESSAGE_MARKER_SIZE) {
+            Some(_) => Ok(datastream),
+            None => {
+                let msg = format!("no {} different characters in a row", MESSAGE_MARKER_SIZE);
+                Err(ParseError::new(1, msg).on_line(Self::DAY, 0))
+            }
+        }
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        Answer::from(part_one(input))
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        Answer::from(part_two(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::solve;
+    use crate::utils::InputSource;
+    use std::path::PathBuf;
+
+    #[test]
+    fn solves_example() {
+        let example = InputSource::Path(PathBuf::from("inputs/examples"));
+        let answers = solve::<Solver>(&example).unwrap().answers;
+        assert_eq!(answers, [Answer::from(7), Answer::from(19)]);
+    }
+
+    #[test]
+    fn solves_input() {
+        let answers = solve::<Solver>(&InputSource::Default).unwrap().answers;
+        assert_eq!(answers, [Answer::from(1361), Answer::from(3263)]);
+    }
+
+    #[test]
+    fn finds_markers() {
+        let datastream = "bvwbjplbgvbhsrlpgdmjqwftvncz".chars().collect_vec();
+        assert_eq!(get_marker_idx(&datastream, 4), Some(5));
+        assert_eq!(get_marker_idx(&datastream, 14), Some(23));
+        assert_eq!(get_marker_idx(&datastream, 27), None);
+
+        let without_marker = Solver::parse(["aaaa"].map(String::from).into_iter());
+        assert!(matches!(without_marker, Err(Error::Parse { line: 1, .. })));
+    }
+}
@@ -0,0 +1,277 @@
+// https://adventofcode.com/2022/day/7
+
+use crate::solution::{Answer, Solution};
+use crate::utils::{parse_number, split_once, Error, ParseError};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::{Rc, Weak};
+
+pub type RCFile = Rc<File>;
+pub type RCDirectory = Rc<Directory>;
+
+pub const DISK_SPACE: u32 = 70000000;
+pub const MIN_REQUIRED_SPACE: u32 = 30000000;
+
+#[derive(Debug, Default)]
+pub struct Directory {
+    name: String,
+    parent: Weak<Directory>,
+    files: RefCell<HashMap<String, RCFile>>,
+    dirs: RefCell<HashMap<String, RCDirectory>>,
+}
+
+impl Directory {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn parent(&self) -> Option<RCDirectory> {
+        self.parent.upgrade()
+    }
+
+    pub fn files(&self) -> Vec<RCFile> {
+        self.files.borrow().values().cloned().collect()
+    }
+
+    pub fn dirs(&self) -> Vec<RCDirectory> {
+        self.dirs.borrow().values().cloned().collect()
+    }
+
+    pub fn size(&self) -> u32 {
+        let files_size: u32 = self.files.borrow().values().map(|file| file.size).sum();
+        let subdirs_size: u32 = self.dirs.borrow().values().map(|dir| dir.size()).sum();
+        files_size + subdirs_size
+    }
+
+    pub fn descendent_dirs(&self) -> Vec<RCDirectory> {
+        let mut subdirs = self.dirs();
+        let mut other_descendents: Vec<RCDirectory> = subdirs
+            .iter()
+            .flat_map(|dir| dir.descendent_dirs())
+            .collect();
+        subdirs.append(&mut other_descendents);
+        subdirs
+    }
+}
+
+#[derive(Debug)]
+pub struct File {
+    name: String,
+    size: u32,
+    _parent: Weak<Directory>,
+}
+
+impl File {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn size(&self) -> u32 {
+        self.size
+    }
+}
+
+pub fn parse_lines(lines: impl Iterator<Item = String>) -> Result<Directory, Error> {
+    let base_dir = Rc::new(Directory {
+        name: String::from("/"),
+        ..Default::default()
+    });
+    let mut curr_dir = Rc::clone(&base_dir);
+
+    let mut parse_line = |line: &str| -> Result<(), ParseError> {
+        let (first_tkn, second_tkn) = split_once(line, " ")?;
+        let second_tkn_column = first_tkn.len() + 2;
+
+        match first_tkn {
+            "$" => match second_tkn {
+                // LS
+                "ls" => {}
+                // CD
+                s if s.starts_with("cd") => {
+                    let (_, subdir) = split_once(second_tkn, " ")?;
+                    let subdir_column = second_tkn_column + "cd ".len();
+
+                    match subdir {
+                        // CD /
+                        "/" => {
+                            curr_dir = Rc::clone(&base_dir);
+                        }
+                        // CD ..
+                        ".." => {
+                            let parent = curr_dir.parent.upgrade().ok_or_else(|| {
+                                ParseError::new(subdir_column, "already at the root directory")
+                            })?;
+                            curr_dir = Rc::clone(&parent);
+                        }
+                        // CD sub-directory
+                        _ => {
+                            let subdirectories = curr_dir.dirs.borrow();
+                            let subdir =
+                                Rc::clone(subdirectories.get(subdir).ok_or_else(|| {
+                                    let msg = format!("unknown directory `{}`", subdir);
+                                    ParseError::new(subdir_column, msg)
+                                })?);
+                            drop(subdirectories);
+                            curr_dir = Rc::clone(&subdir);
+                        }
+                    }
+                }
+                command => {
+                    let msg = format!("unknown command `{}`", command);
+                    return Err(ParseError::new(second_tkn_column, msg));
+                }
+            },
+
+            // dir <directory-name>
+            // Listing a directory again keeps what's already known to be in its subdirectories
+            "dir" => {
+                let parent = Rc::downgrade(&curr_dir);
+                curr_dir
+                    .dirs
+                    .borrow_mut()
+                    .entry(String::from(second_tkn))
+                    .or_insert_with(|| {
+                        Rc::new(Directory {
+                            name: second_tkn.to_string(),
+                            parent,
+                            ..Default::default()
+                        })
+                    });
+            }
+            // <size> <file-name>
+            _ => {
+                let new_file = Rc::new(File {
+                    size: parse_number(first_tkn, 1)?,
+                    name: String::from(second_tkn),
+                    _parent: Rc::downgrade(&curr_dir),
+                });
+                curr_dir
+                    .files
+                    .borrow_mut()
+                    .insert(String::from(second_tkn), new_file);
+            }
+        }
+        Ok(())
+    };
+
+    let mut num_lines: usize = 0;
+    for (line_idx, line) in lines.enumerate() {
+        parse_line(&line).map_err(|err| err.on_line(Solver::DAY, line_idx))?;
+        num_lines += 1;
+    }
+
+    let used_space = total_size(&base_dir);
+    if used_space > DISK_SPACE as u64 {
+        let msg = format!(
+            "files take up {}, more than the disk's {}",
+            used_space, DISK_SPACE
+        );
+        let err = ParseError::new(1, msg);
+        return Err(err.on_line(Solver::DAY, num_lines.saturating_sub(1)));
+    }
+
+    // Release the last visited directory, which may be the base directory itself
+    drop(curr_dir);
+    Ok(Rc::try_unwrap(base_dir).unwrap())
+}
+
+// The size of a directory, counted in a u64 as its files may not fit on the disk
+fn total_size(dir: &Directory) -> u64 {
+    let files_size: u64 = dir
+        .files
+        .borrow()
+        .values()
+        .map(|file| file.size as u64)
+        .sum();
+    let subdirs_size: u64 = dir.dirs.borrow().values().map(|dir| total_size(dir)).sum();
+    files_size + subdirs_size
+}
+
+pub fn part_one(file_system: &Directory) -> u32 {
+    file_system
+        .descendent_dirs()
+        .iter()
+        .map(|dir| dir.size())
+        .filter(|size| size <= &100000)
+        .sum()
+}
+
+// The size of the smallest directory (maybe the root) to delete to leave enough unused space,
+// or 0 when there's enough already. Parsing makes sure the files fit on the disk.
+pub fn part_two(file_system: &Directory) -> u32 {
+    let unused_space: u32 = DISK_SPACE - file_system.size();
+    if unused_space >= MIN_REQUIRED_SPACE {
+        return 0;
+    }
+    let space_to_free = MIN_REQUIRED_SPACE - unused_space;
+    let dirs = file_system.descendent_dirs();
+    let sizes = dirs.iter().map(|dir| dir.size());
+    sizes
+        .chain([file_system.size()])
+        .filter(|size| size >= &space_to_free)
+        .min()
+        .unwrap()
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const DAY: u8 = 7;
+    type Input = Directory;
+
+    fn parse(lines: impl Iterator<Item = String>) -> Result<Self::Input, Error> {
+        parse_lines(lines)
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        Answer::from(part_one(input))
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        Answer::from(part_two(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::solve;
+    use crate::utils::InputSource;
+    use std::path::PathBuf;
+
+    #[test]
+    fn solves_example() {
+        let example = InputSource::Path(PathBuf::from("inputs/examples"));
+        let answers = solve::<Solver>(&example).unwrap().answers;
+        assert_eq!(answers, [Answer::from(95437), Answer::from(24933642)]);
+    }
+
+    #[test]
+    fn solves_input() {
+        let answers = solve::<Solver>(&InputSource::Default).unwrap().answers;
+        assert_eq!(answers, [Answer::from(1555642), Answer::from(5974547)]);
+    }
+
+    #[test]
+    fn handles_disks_with_enough_or_too_little_space() {
+        let parse = |input: &str| Solver::parse(input.lines().map(String::from));
+        let small = parse("$ cd /\n$ ls\ndir a\n100 b.txt").unwrap();
+        assert_eq!(part_two(&small), 0);
+
+        // Only deleting everything frees enough space
+        let full = parse("$ cd /\n$ ls\n50000000 big.bin").unwrap();
+        assert_eq!(part_two(&full), 50000000);
+
+        let overfull = parse("$ cd /\n$ ls\n40000000 a.bin\n40000000 b.bin");
+        assert!(matches!(overfull, Err(Error::Parse { line: 4, .. })));
+
+        // Listing directories again doesn't count their files twice
+        let relisted = parse(
+            "$ cd /\n$ ls\ndir a\n$ cd a\n$ ls\n40000000 a.bin\n\
+             $ cd /\n$ ls\ndir a\n$ cd a\n$ ls\n40000000 a.bin",
+        )
+        .unwrap();
+        assert_eq!(relisted.size(), 40000000);
+        assert_eq!(part_two(&relisted), 0);
+    }
+}
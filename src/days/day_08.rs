@@ -1,27 +1,44 @@
 // https://adventofcode.com/2022/day/8
-#![feature(type_alias_impl_trait)]
 
-mod utils;
 use std::collections::{HashMap, HashSet};
 use std::iter::Iterator;
 use std::ops::Range;
 
 use itertools::Itertools;
 
-use crate::utils::input_for_day;
-
-type TreePos = (usize, usize);
-type TreePosIter = impl DoubleEndedIterator<Item = TreePos>;
-type NestedTreePosIter = impl Iterator<Item = TreePosIter>;
-
-fn parse_lines(lines: impl Iterator<Item = String>) -> Vec<Vec<u8>> {
-    lines
-        .map(|line| {
-            line.chars()
-                .map(|chr| chr.to_digit(10).unwrap() as u8)
-                .collect()
-        })
-        .collect()
+use crate::solution::{Answer, Solution};
+use crate::utils::{parse_each_line, Error, ParseError};
+
+pub type TreePos = (usize, usize);
+type TreePosIter = std::vec::IntoIter<TreePos>;
+type NestedTreePosIter = Box<dyn Iterator<Item = TreePosIter>>;
+
+// The parts expect a non-empty grid with as many trees in every row
+pub fn parse_lines(lines: impl Iterator<Item = String>) -> Result<Vec<Vec<u8>>, Error> {
+    let tree_grid: Vec<Vec<u8>> = parse_each_line(Solver::DAY, lines.enumerate(), |line| {
+        line.chars()
+            .enumerate()
+            .map(|(idx, chr)| {
+                let height = chr.to_digit(10).ok_or_else(|| {
+                    ParseError::new(idx + 1, format!("invalid tree height `{}`", chr))
+                })?;
+                Ok(height as u8)
+            })
+            .collect()
+    })?;
+
+    let num_cols = tree_grid.first().map_or(0, |row| row.len());
+    if num_cols == 0 {
+        return Err(ParseError::new(1, "expected a row of trees").on_line(Solver::DAY, 0));
+    }
+    match tree_grid.iter().position(|row| row.len() != num_cols) {
+        Some(row_idx) => {
+            let column = tree_grid[row_idx].len().min(num_cols) + 1;
+            let msg = format!("expected {} trees, as in the first row", num_cols);
+            Err(ParseError::new(column, msg).on_line(Solver::DAY, row_idx))
+        }
+        None => Ok(tree_grid),
+    }
 }
 
 // Iterates through two ranges of indices, row indices and col indices and constructs a nested Iterator
@@ -33,7 +50,7 @@ fn tree_lines_for_ranges(
     b_major: bool,
     reversed: bool,
 ) -> NestedTreePosIter {
-    range_a.map(move |row_idx| {
+    Box::new(range_a.map(move |row_idx| {
         let pos_iter = range_b.clone().map(move |col_idx| match b_major {
             true => (col_idx, row_idx),
             false => (row_idx, col_idx),
@@ -42,10 +59,10 @@ fn tree_lines_for_ranges(
             true => pos_iter.rev().collect::<Vec<TreePos>>().into_iter(),
             false => pos_iter.collect::<Vec<TreePos>>().into_iter(),
         }
-    })
+    }))
 }
 
-fn get_visible_trees_pos(mut tree_line: TreePosIter, tree_grid: &Vec<Vec<u8>>) -> HashSet<TreePos> {
+fn get_visible_trees_pos(mut tree_line: TreePosIter, tree_grid: &[Vec<u8>]) -> HashSet<TreePos> {
     let (first_tree_row, first_tree_col) = tree_line.next().unwrap();
     let mut curr_max_height = tree_grid[first_tree_row][first_tree_col];
     let mut visible_trees = HashSet::new();
@@ -61,20 +78,19 @@ fn get_visible_trees_pos(mut tree_line: TreePosIter, tree_grid: &Vec<Vec<u8>>) -
     visible_trees
 }
 
-fn part_one() -> u32 {
-    let tree_grid = parse_lines(input_for_day(8));
+pub fn part_one(tree_grid: &[Vec<u8>]) -> u32 {
     let (num_rows, num_cols) = (tree_grid.len(), tree_grid[0].len());
     let is_on_edge =
         |(x, y): &TreePos| *x == 0 || *x == num_rows - 1 || *y == 0 || *y == num_cols - 1;
 
     // Rows and cols (iterators) to check for tree line
     let rows: NestedTreePosIter = tree_lines_for_ranges(0..num_rows, 0..num_cols, false, false);
-    let cols: NestedTreePosIter = tree_lines_for_ranges(0..num_cols, 0..num_cols, true, false);
+    let cols: NestedTreePosIter = tree_lines_for_ranges(0..num_cols, 0..num_rows, true, false);
     let rev_rows: NestedTreePosIter = tree_lines_for_ranges(0..num_rows, 0..num_cols, false, true);
     let rev_cols: NestedTreePosIter = tree_lines_for_ranges(0..num_cols, 0..num_rows, true, true);
 
     // Edge trees are directly visible
-    let edge_positions = tree_lines_for_ranges(0..num_cols, 0..num_rows, false, false)
+    let edge_positions = tree_lines_for_ranges(0..num_rows, 0..num_cols, false, false)
         .flatten()
         .filter(is_on_edge);
 
@@ -82,7 +98,7 @@ fn part_one() -> u32 {
     let mut positions: HashSet<TreePos> = HashSet::from_iter(edge_positions);
     for line_iter in [rows, cols, rev_rows, rev_cols] {
         for line in line_iter {
-            positions.extend(get_visible_trees_pos(line, &tree_grid));
+            positions.extend(get_visible_trees_pos(line, tree_grid));
         }
     }
 
@@ -100,22 +116,18 @@ pub fn compute_num_visible_trees(tree_line: Vec<u8>) -> Vec<usize> {
         }
         stack.push(idx);
     }
-    while !stack.is_empty() {
-        let i = stack.pop().unwrap();
+    while let Some(i) = stack.pop() {
         num_visible_trees[i] = tree_line.len() - i - 1;
     }
     num_visible_trees
 }
 
-fn part_two() -> u32 {
-    let tree_grid = parse_lines(input_for_day(8));
+pub fn part_two(tree_grid: &[Vec<u8>]) -> u32 {
     let (num_rows, num_cols) = (tree_grid.len(), tree_grid[0].len());
-    let is_on_edge =
-        |(x, y): &TreePos| *x == 0 || *x == num_rows - 1 || *y == 0 || *y == num_cols - 1;
 
     // Rows and cols (iterators) to check for tree line
     let rows: NestedTreePosIter = tree_lines_for_ranges(0..num_rows, 0..num_cols, false, false);
-    let cols: NestedTreePosIter = tree_lines_for_ranges(0..num_cols, 0..num_cols, true, false);
+    let cols: NestedTreePosIter = tree_lines_for_ranges(0..num_cols, 0..num_rows, true, false);
     let rev_rows: NestedTreePosIter = tree_lines_for_ranges(0..num_rows, 0..num_cols, false, true);
     let rev_cols: NestedTreePosIter = tree_lines_for_ranges(0..num_cols, 0..num_rows, true, true);
 
@@ -131,8 +143,8 @@ fn part_two() -> u32 {
             let num_visible_trees = compute_num_visible_trees(tree_line);
 
             for (pos, num_visible) in indices.iter().zip(num_visible_trees.iter()) {
-                if num_visible_from_pos.contains_key(&pos) {
-                    *num_visible_from_pos.get_mut(&pos).unwrap() *= num_visible;
+                if num_visible_from_pos.contains_key(pos) {
+                    *num_visible_from_pos.get_mut(pos).unwrap() *= num_visible;
                 } else {
                     num_visible_from_pos.insert(*pos, *num_visible);
                 }
@@ -143,8 +155,76 @@ fn part_two() -> u32 {
     *num_visible_from_pos.values().max().unwrap() as u32
 }
 
-fn main() -> Result<(), std::io::Error> {
-    println!("{}", part_one());
-    println!("{}", part_two());
-    Ok(())
+pub struct Solver;
+
+impl Solution for Solver {
+    const DAY: u8 = 8;
+    type Input = Vec<Vec<u8>>;
+
+    fn parse(lines: impl Iterator<Item = String>) -> Result<Self::Input, Error> {
+        parse_lines(lines)
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        Answer::from(part_one(input))
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        Answer::from(part_two(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::solve;
+    use crate::utils::InputSource;
+    use std::path::PathBuf;
+
+    #[test]
+    fn solves_example() {
+        let example = InputSource::Path(PathBuf::from("inputs/examples"));
+        let answers = solve::<Solver>(&example).unwrap().answers;
+        assert_eq!(answers, [Answer::from(21), Answer::from(8)]);
+    }
+
+    #[test]
+    fn solves_input() {
+        let answers = solve::<Solver>(&InputSource::Default).unwrap().answers;
+        assert_eq!(answers, [Answer::from(1705), Answer::from(371200)]);
+    }
+
+    #[test]
+    fn solves_grids_which_are_not_square() {
+        let rows = ["30373", "25512", "65332", "33549"].map(String::from);
+        let tree_grid = parse_lines(rows.into_iter()).unwrap();
+        assert_eq!(part_one(&tree_grid), 18);
+        assert_eq!(part_two(&tree_grid), 4);
+    }
+
+    #[test]
+    fn reports_grids_which_are_empty_or_ragged() {
+        let parse = |input: &[&str]| parse_lines(input.iter().map(|line| line.to_string()));
+        assert!(matches!(parse(&[]), Err(Error::Parse { line: 1, .. })));
+        assert!(matches!(
+            parse(&["123", "45", "678"]),
+            Err(Error::Parse {
+                line: 2,
+                column: 3,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn counts_trees_visible_up_to_the_first_blocking_tree() {
+        assert_eq!(
+            compute_num_visible_trees(vec![3, 0, 3, 7, 3]),
+            vec![2, 1, 1, 1, 0]
+        );
+        assert_eq!(
+            compute_num_visible_trees(vec![2, 5, 5, 1, 2]),
+            vec![1, 1, 2, 1, 0]
+        );
+    }
 }
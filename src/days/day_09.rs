@@ -0,0 +1,241 @@
+// https://adventofcode.com/2022/day/9
+
+use std::collections::{HashMap, HashSet};
+use std::iter::Iterator;
+use std::ops::Add;
+
+use crate::solution::{Answer, Solution};
+use crate::utils::{parse_each_line, parse_number, split_once, Error, ParseError};
+use std::str::FromStr;
+
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+pub struct Pos {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl<'a> Add<&'a Pos> for Pos {
+    type Output = Pos;
+    fn add(self, other: &'a Pos) -> Pos {
+        Pos {
+            x: self.x + other.x,
+            y: self.y + other.y,
+        }
+    }
+}
+
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    pub fn delta(&self) -> Pos {
+        match *self {
+            Direction::Up => Pos { x: 0, y: 1 },
+            Direction::Down => Pos { x: 0, y: -1 },
+            Direction::Left => Pos { x: -1, y: 0 },
+            Direction::Right => Pos { x: 1, y: 0 },
+        }
+    }
+}
+
+pub struct Move {
+    pub dir: Direction,
+    pub steps: u32,
+}
+
+impl FromStr for Move {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let (dir_str, steps_str) = split_once(line, " ")?;
+        let steps_column = dir_str.len() + 2;
+        if steps_str.starts_with('-') {
+            let msg = format!("can't move a negative number of steps `{}`", steps_str);
+            return Err(ParseError::new(steps_column, msg));
+        }
+        let steps = parse_number::<u32>(steps_str, steps_column)?;
+
+        match dir_str {
+            "U" => Ok(Move {
+                dir: Direction::Up,
+                steps,
+            }),
+            "D" => Ok(Move {
+                dir: Direction::Down,
+                steps,
+            }),
+            "L" => Ok(Move {
+                dir: Direction::Left,
+                steps,
+            }),
+            "R" => Ok(Move {
+                dir: Direction::Right,
+                steps,
+            }),
+            _ => Err(ParseError::new(
+                1,
+                format!("unknown direction `{}`", dir_str),
+            )),
+        }
+    }
+}
+
+pub fn is_adjacent(head: Pos, tail: Pos) -> bool {
+    (head.x - tail.x).abs() <= 1 && (head.y - tail.y).abs() <= 1
+}
+
+pub fn next_tail_pos(head: Pos, tail: Pos) -> Pos {
+    if is_adjacent(head, tail) {
+        return tail;
+    }
+
+    let x_diff = head.x - tail.x;
+    let y_diff = head.y - tail.y;
+
+    // Same column
+    if x_diff == 0 && y_diff.abs() == 1 {
+        return Pos {
+            x: tail.x,
+            y: tail.y + y_diff.signum(),
+        };
+    }
+
+    // Same row
+    if y_diff == 0 && x_diff.abs() == 1 {
+        return Pos {
+            x: tail.x + x_diff.signum(),
+            y: tail.y,
+        };
+    }
+
+    // Diagonal
+    Pos {
+        x: tail.x + x_diff.signum(),
+        y: tail.y + y_diff.signum(),
+    }
+}
+
+pub fn part_one(moves: &[Move]) -> usize {
+    let mut head = Pos { x: 0, y: 0 };
+    let mut tail = Pos { x: 0, y: 0 };
+    let mut visited: HashSet<Pos> = HashSet::new();
+    visited.insert(tail);
+
+    for _move in moves {
+        for _ in 0.._move.steps {
+            head = head + &_move.dir.delta();
+            tail = next_tail_pos(head, tail);
+            visited.insert(tail);
+        }
+    }
+
+    visited.len()
+}
+
+pub fn part_two(moves: &[Move]) -> usize {
+    let mut positions: HashMap<i32, Pos> =
+        HashMap::from_iter((0..10).map(|part| (part, Pos { x: 0, y: 0 })));
+    let mut visited: HashSet<Pos> = HashSet::new();
+    visited.insert(positions[&9]);
+
+    for _move in moves {
+        for _ in 0.._move.steps {
+            positions.insert(0, *positions.get(&0).unwrap() + &_move.dir.delta());
+
+            for part in 1..10 {
+                positions.insert(
+                    part,
+                    next_tail_pos(
+                        *positions.get(&(&part - 1)).unwrap(),
+                        *positions.get(&part).unwrap(),
+                    ),
+                );
+            }
+            visited.insert(*positions.get(&9).unwrap());
+        }
+    }
+
+    visited.len()
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const DAY: u8 = 9;
+    type Input = Vec<Move>;
+
+    fn parse(lines: impl Iterator<Item = String>) -> Result<Self::Input, Error> {
+        parse_each_line(Self::DAY, lines.enumerate(), Move::from_str)
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        Answer::from(part_one(input))
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        Answer::from(part_two(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::solve;
+    use crate::utils::InputSource;
+    use std::path::PathBuf;
+
+    #[test]
+    fn solves_example() {
+        let example = InputSource::Path(PathBuf::from("inputs/examples"));
+        let answers = solve::<Solver>(&example).unwrap().answers;
+        assert_eq!(answers, [Answer::from(13), Answer::from(1)]);
+    }
+
+    #[test]
+    fn solves_input() {
+        let answers = solve::<Solver>(&InputSource::Default).unwrap().answers;
+        assert_eq!(answers, [Answer::from(6030), Answer::from(2545)]);
+    }
+
+    #[test]
+    fn solves_larger_example() {
+        let example = InputSource::Path(PathBuf::from("inputs/examples/day-09-larger.txt"));
+        let [_, part_two] = solve::<Solver>(&example).unwrap().answers;
+        assert_eq!(part_two, Answer::from(36));
+    }
+
+    #[test]
+    fn rejects_negative_steps() {
+        let parse = |input: &[&str]| Solver::parse(input.iter().map(|line| line.to_string()));
+        assert!(matches!(
+            parse(&["R 4", "U -5"]),
+            Err(Error::Parse {
+                line: 2,
+                column: 3,
+                ..
+            })
+        ));
+        // Far more steps than an i16 position could hold
+        let moves = parse(&["R 30000", "R 30000", "L 1"]).unwrap();
+        assert_eq!(part_one(&moves), 60000);
+    }
+
+    #[test]
+    fn tail_follows_head() {
+        let pos = |x, y| Pos { x, y };
+        // Touching (or overlapping) knots don't move
+        assert!(next_tail_pos(pos(1, 1), pos(0, 0)) == pos(0, 0));
+        assert!(next_tail_pos(pos(0, 0), pos(0, 0)) == pos(0, 0));
+        // Same row or column
+        assert!(next_tail_pos(pos(2, 0), pos(0, 0)) == pos(1, 0));
+        assert!(next_tail_pos(pos(0, -2), pos(0, 0)) == pos(0, -1));
+        // Diagonal
+        assert!(next_tail_pos(pos(1, 2), pos(0, 0)) == pos(1, 1));
+        assert!(next_tail_pos(pos(-2, 1), pos(0, 0)) == pos(-1, 1));
+        assert!(next_tail_pos(pos(2, 2), pos(0, 0)) == pos(1, 1));
+    }
+}
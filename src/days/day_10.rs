@@ -0,0 +1,154 @@
+// https://adventofcode.com/2022/day/10
+
+use crate::solution::{Answer, Solution};
+use crate::utils::{parse_each_line, parse_number, split_once, Error, ParseError};
+use std::iter::Iterator;
+use std::str::FromStr;
+
+pub enum Inst {
+    NoOp,
+    AddX(i16),
+}
+
+impl FromStr for Inst {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        match line {
+            "noop" => Ok(Inst::NoOp),
+            _ => {
+                let (inst, val) = split_once(line, " ")?;
+                match inst {
+                    "addx" => Ok(Inst::AddX(parse_number(val, inst.len() + 2)?)),
+                    _ => Err(ParseError::new(
+                        1,
+                        format!("unknown instruction `{}`", inst),
+                    )),
+                }
+            }
+        }
+    }
+}
+
+// The value of the X register during each cycle, in order starting from the first cycle
+pub fn x_during_cycles(instructions: &[Inst]) -> Vec<i64> {
+    let mut x = 1;
+    let mut x_values = Vec::new();
+
+    for inst in instructions {
+        match inst {
+            Inst::AddX(val) => {
+                x_values.extend([x, x]);
+                x += *val as i64;
+            }
+            Inst::NoOp => x_values.push(x),
+        }
+    }
+    x_values
+}
+
+pub fn part_one(instructions: &[Inst]) -> i64 {
+    let cycles = (1..).zip(x_during_cycles(instructions));
+    cycles
+        .filter(|(cycle, _)| (cycle - 20) % 40 == 0)
+        .map(|(cycle, x)| cycle * x)
+        .sum()
+}
+
+pub type CRTRow<'a> = [&'a str; 40];
+pub type CRTPanel<'a> = [CRTRow<'a>; 6];
+
+pub trait StringSerializable {
+    fn to_str(&self) -> String;
+}
+
+impl StringSerializable for CRTPanel<'_> {
+    fn to_str(&self) -> String {
+        self.map(|row| row.join(" ")).join("\n")
+    }
+}
+
+pub fn part_two(instructions: &[Inst]) -> CRTPanel<'static> {
+    let mut panel: CRTPanel = [["."; 40]; 6];
+    let x_values = x_during_cycles(instructions);
+
+    // The pixel drawn during a cycle is indexed by the number of cycles completed before it
+    for (pixel_idx, x) in x_values.into_iter().take(40 * 6).enumerate() {
+        let (row, column) = (pixel_idx / 40, pixel_idx % 40);
+        if (column as i64 - x).abs() <= 1 {
+            panel[row][column] = "#";
+        }
+    }
+    panel
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const DAY: u8 = 10;
+    type Input = Vec<Inst>;
+
+    fn parse(lines: impl Iterator<Item = String>) -> Result<Self::Input, Error> {
+        parse_each_line(Self::DAY, lines.enumerate(), Inst::from_str)
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        Answer::from(part_one(input))
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        Answer::from(part_two(input).to_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::solve;
+    use crate::utils::InputSource;
+    use itertools::Itertools;
+    use std::path::PathBuf;
+
+    fn render(rows: [&str; 6]) -> Answer {
+        Answer::from(rows.map(|row| row.chars().join(" ")).join("\n"))
+    }
+
+    #[test]
+    fn solves_example() {
+        let example = InputSource::Path(PathBuf::from("inputs/examples"));
+        let answers = solve::<Solver>(&example).unwrap().answers;
+        let image = render([
+            "##..##..##..##..##..##..##..##..##..##..",
+            "###...###...###...###...###...###...###.",
+            "####....####....####....####....####....",
+            "#####.....#####.....#####.....#####.....",
+            "######......######......######......####",
+            "#######.......#######.......#######.....",
+        ]);
+        assert_eq!(answers, [Answer::from(13140), image]);
+    }
+
+    #[test]
+    fn solves_input() {
+        let answers = solve::<Solver>(&InputSource::Default).unwrap().answers;
+        let image = render([
+            "####...##..##..####.###...##..#....#..#.",
+            "#.......#.#..#.#....#..#.#..#.#....#..#.",
+            "###.....#.#....###..#..#.#....#....####.",
+            "#.......#.#....#....###..#.##.#....#..#.",
+            "#....#..#.#..#.#....#....#..#.#....#..#.",
+            "####..##...##..#....#.....###.####.#..#.",
+        ]);
+        assert_eq!(answers, [Answer::from(11960), image]);
+    }
+
+    #[test]
+    fn tracks_x_during_each_cycle() {
+        let instructions = [Inst::NoOp, Inst::AddX(3), Inst::AddX(-5)];
+        assert_eq!(x_during_cycles(&instructions), [1, 1, 1, 4, 4]);
+
+        // Signal strengths well past what an i32 holds
+        let instructions = (0..2000).map(|_| Inst::AddX(30000)).collect_vec();
+        assert_eq!(part_one(&instructions), 7_993_800_200_000);
+    }
+}
@@ -0,0 +1,79 @@
+use crate::solution::{
+    bench, render_report, solve, BenchReport, InputReport, Report, ReportOptions, Solution,
+};
+use crate::utils::{Error, InputSource};
+
+pub mod day_01;
+pub mod day_02;
+pub mod day_03;
+pub mod day_04;
+pub mod day_05;
+pub mod day_06;
+pub mod day_07;
+pub mod day_08;
+pub mod day_09;
+pub mod day_10;
+
+pub struct Day {
+    pub number: u8,
+    pub solve: fn(&InputSource) -> Result<Report, Error>,
+    pub bench: fn(&InputSource, usize) -> Result<BenchReport, Error>,
+}
+
+impl Day {
+    const fn of<S: Solution>() -> Self {
+        Day {
+            number: S::DAY,
+            solve: solve::<S>,
+            bench: bench::<S>,
+        }
+    }
+}
+
+// Registry of every solved day, in calendar order
+pub static DAYS: [Day; 10] = [
+    Day::of::<day_01::Solver>(),
+    Day::of::<day_02::Solver>(),
+    Day::of::<day_03::Solver>(),
+    Day::of::<day_04::Solver>(),
+    Day::of::<day_05::Solver>(),
+    Day::of::<day_06::Solver>(),
+    Day::of::<day_07::Solver>(),
+    Day::of::<day_08::Solver>(),
+    Day::of::<day_09::Solver>(),
+    Day::of::<day_10::Solver>(),
+];
+
+pub fn find(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
+
+pub struct DayReport {
+    pub day: u8,
+    pub name: &'static str,
+    pub about: &'static str,
+    pub render: fn(&InputSource, &ReportOptions) -> Result<String, Error>,
+}
+
+impl DayReport {
+    const fn of<R: InputReport>() -> Self {
+        DayReport {
+            day: R::Solver::DAY,
+            name: R::NAME,
+            about: R::ABOUT,
+            render: render_report::<R>,
+        }
+    }
+}
+
+// Registry of the reports about days' inputs
+pub static REPORTS: [DayReport; 4] = [
+    DayReport::of::<day_01::CalorieReport>(),
+    DayReport::of::<day_02::StrategyReport>(),
+    DayReport::of::<day_03::BadgeReport>(),
+    DayReport::of::<day_03::ReorganisationReport>(),
+];
+
+pub fn reports_for(day: u8) -> impl Iterator<Item = &'static DayReport> {
+    REPORTS.iter().filter(move |report| report.day == day)
+}
@@ -0,0 +1,184 @@
+// Downloads puzzle inputs into the input directory, so that each one is only ever fetched once
+// (as asked by Advent of Code).
+
+use crate::utils::Error;
+use log::info;
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+// The value of the `session` cookie of a logged in Advent of Code account
+pub const SESSION_VAR: &str = "AOC_SESSION";
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+const USER_AGENT: &str = concat!("advent-2022/", env!("CARGO_PKG_VERSION"));
+
+// Requests to Advent of Code on behalf of a logged in account, for fetching inputs and
+// submitting answers
+#[derive(Debug, Clone)]
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(base_url: impl Into<String>, session: impl Into<String>) -> Self {
+        Client {
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            session: session.into(),
+            agent: ureq::Agent::config_builder()
+                .timeout_global(Some(Duration::from_secs(30)))
+                .build()
+                .into(),
+        }
+    }
+
+    // Only available when a session is set, as inputs and answers differ between accounts
+    pub fn from_env() -> Option<Self> {
+        let session = env::var(SESSION_VAR)
+            .ok()
+            .filter(|session| !session.is_empty())?;
+        let base_url = env::var(BASE_URL_VAR).unwrap_or_else(|_| String::from(DEFAULT_BASE_URL));
+        Some(Client::new(base_url, session))
+    }
+
+    // The URL of a page of the day's puzzle, such as `input`
+    pub fn url(&self, day: u8, page: &str) -> String {
+        format!("{}/2022/day/{}/{}", self.base_url, day, page)
+    }
+
+    // The body of the page at `url`
+    pub fn get(&self, url: &str) -> Result<String, ureq::Error> {
+        let mut response = self
+            .agent
+            .get(url)
+            .header("Cookie", &format!("session={}", self.session))
+            .header("User-Agent", USER_AGENT)
+            .call()?;
+        response.body_mut().read_to_string()
+    }
+
+    // The body of the page answering the form posted to `url`
+    pub fn post_form(&self, url: &str, form: [(&str, String); 2]) -> Result<String, ureq::Error> {
+        let mut response = self
+            .agent
+            .post(url)
+            .header("Cookie", &format!("session={}", self.session))
+            .header("User-Agent", USER_AGENT)
+            .send_form(form)?;
+        response.body_mut().read_to_string()
+    }
+}
+
+pub struct Fetcher {
+    client: Client,
+}
+
+impl Fetcher {
+    pub fn new(base_url: impl Into<String>, session: impl Into<String>) -> Self {
+        Fetcher::with_client(Client::new(base_url, session))
+    }
+
+    pub fn with_client(client: Client) -> Self {
+        Fetcher { client }
+    }
+
+    pub fn from_env() -> Option<Self> {
+        Client::from_env().map(Fetcher::with_client)
+    }
+
+    pub fn url(&self, day: u8) -> String {
+        self.client.url(day, "input")
+    }
+
+    pub fn fetch(&self, day: u8) -> Result<String, Error> {
+        let url = self.url(day);
+        let fetch_error = |message: String| Error::Fetch {
+            day,
+            url: url.clone(),
+            message,
+        };
+        info!("Fetching input from {}", url);
+        self.client.get(&url).map_err(|err| match err {
+            // Advent of Code answers with a 400 when the session has expired
+            ureq::Error::StatusCode(400) => {
+                fetch_error(format!("the session in ${} was rejected", SESSION_VAR))
+            }
+            ureq::Error::StatusCode(404) => {
+                fetch_error(String::from("the puzzle isn't unlocked yet"))
+            }
+            err => fetch_error(err.to_string()),
+        })
+    }
+
+    // Fetches the input into `path`, unless it's already there. Empty files are placeholders
+    // (e.g. from `aoc new`), so they're fetched into too.
+    pub fn fetch_into(&self, day: u8, path: &Path) -> Result<(), Error> {
+        if fs::metadata(path).is_ok_and(|meta| meta.len() > 0) {
+            return Ok(());
+        }
+        let input = self.fetch(day)?;
+        let io_error = |source| Error::Io {
+            path: path.to_path_buf(),
+            source,
+        };
+
+        // Write to a temporary file first so that an interrupted write isn't taken for the input
+        let partial_path = path.with_extension("part");
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(io_error)?;
+        }
+        fs::write(&partial_path, input).map_err(io_error)?;
+        fs::rename(&partial_path, path).map_err(io_error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stub_server::serve_once;
+    use std::path::PathBuf;
+
+    fn cache_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-fetch-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn fetches_missing_inputs_once() {
+        let (base_url, server) = serve_once("200 OK", "1000\n2000\n");
+        let fetcher = Fetcher::new(base_url + "/", "abc123");
+        let dir = cache_dir("once");
+        let path = dir.join("day-01.txt");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(&path, "").unwrap();
+
+        fetcher.fetch_into(1, &path).unwrap();
+        let request_head = server.join().unwrap();
+        assert!(request_head.starts_with("GET /2022/day/1/input HTTP/1.1"));
+        assert!(request_head.contains("session=abc123"));
+        assert!(request_head.contains("user-agent: advent-2022/"));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1000\n2000\n");
+
+        // The server is gone, so this would fail if it fetched again
+        fetcher.fetch_into(1, &path).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn reports_failed_fetches() {
+        let (base_url, server) = serve_once("404 Not Found", "");
+        let fetcher = Fetcher::new(base_url, "abc123");
+        let dir = cache_dir("failed");
+        let path = dir.join("day-25.txt");
+
+        let err = fetcher.fetch_into(25, &path).unwrap_err();
+        server.join().unwrap();
+        assert!(matches!(err, Error::Fetch { day: 25, .. }));
+        assert!(!path.exists());
+    }
+}
@@ -0,0 +1,268 @@
+use itertools::Itertools;
+use std::cmp::{max, min};
+use std::fmt;
+
+// An inclusive range of integers, which always holds at least one of them
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Interval {
+    start: i64,
+    end: i64,
+}
+
+impl Interval {
+    pub fn new(start: i64, end: i64) -> Self {
+        assert!(start <= end, "interval {}-{} is empty", start, end);
+        Interval { start, end }
+    }
+
+    pub fn start(&self) -> i64 {
+        self.start
+    }
+
+    pub fn end(&self) -> i64 {
+        self.end
+    }
+
+    // How many integers are in the interval, which is one more than fits in a u64 when it
+    // spans every i64
+    pub fn length(&self) -> u128 {
+        self.start.abs_diff(self.end) as u128 + 1
+    }
+
+    pub fn contains(&self, val: i64) -> bool {
+        self.start <= val && val <= self.end
+    }
+
+    pub fn contains_interval(&self, other: &Interval) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    pub fn overlaps(&self, other: &Interval) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+
+    // Whether the intervals overlap or one starts right after the other ends
+    pub fn touches(&self, other: &Interval) -> bool {
+        self.start <= other.end.saturating_add(1) && other.start <= self.end.saturating_add(1)
+    }
+
+    pub fn intersection(&self, other: &Interval) -> Option<Interval> {
+        let (start, end) = (max(self.start, other.start), min(self.end, other.end));
+        (start <= end).then_some(Interval { start, end })
+    }
+
+    // The single interval covering both, when there's no gap between them
+    pub fn union(&self, other: &Interval) -> Option<Interval> {
+        self.touches(other).then(|| Interval {
+            start: min(self.start, other.start),
+            end: max(self.end, other.end),
+        })
+    }
+
+    // What's left after taking out `other`, which can be split in two
+    pub fn difference(&self, other: &Interval) -> IntervalSet {
+        IntervalSet::from(*self).difference(&IntervalSet::from(*other))
+    }
+}
+
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", self.start, self.end)
+    }
+}
+
+// A set of integers held as the fewest intervals covering them: sorted, and with a gap between
+// each interval and the next
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IntervalSet {
+    intervals: Vec<Interval>,
+}
+
+impl IntervalSet {
+    pub fn new() -> Self {
+        IntervalSet::default()
+    }
+
+    pub fn intervals(&self) -> &[Interval] {
+        &self.intervals
+    }
+
+    // How many integers are in the set
+    pub fn len(&self) -> u128 {
+        self.intervals.iter().map(Interval::length).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    pub fn contains(&self, val: i64) -> bool {
+        let idx = self
+            .intervals
+            .partition_point(|interval| interval.end < val);
+        self.intervals
+            .get(idx)
+            .is_some_and(|interval| interval.contains(val))
+    }
+
+    pub fn insert(&mut self, interval: Interval) {
+        // The intervals overlapping or adjacent to the new one are merged into it
+        let first = self
+            .intervals
+            .partition_point(|other| other.end.saturating_add(1) < interval.start);
+        let last = self
+            .intervals
+            .partition_point(|other| other.start <= interval.end.saturating_add(1));
+        let merged = self.intervals[first..last]
+            .iter()
+            .fold(interval, |merged, other| merged.union(other).unwrap());
+        self.intervals.splice(first..last, [merged]);
+    }
+
+    pub fn union(&self, other: &IntervalSet) -> IntervalSet {
+        let mut union = self.clone();
+        union.extend(other.intervals.iter().copied());
+        union
+    }
+
+    pub fn intersection(&self, other: &IntervalSet) -> IntervalSet {
+        let (mut idx, mut other_idx) = (0, 0);
+        let mut intervals = Vec::new();
+        while let (Some(interval), Some(other_interval)) =
+            (self.intervals.get(idx), other.intervals.get(other_idx))
+        {
+            intervals.extend(interval.intersection(other_interval));
+            // Whichever interval ends first can't overlap anything else in the other set
+            match interval.end <= other_interval.end {
+                true => idx += 1,
+                false => other_idx += 1,
+            }
+        }
+        IntervalSet { intervals }
+    }
+
+    pub fn difference(&self, other: &IntervalSet) -> IntervalSet {
+        let mut intervals = Vec::new();
+        for interval in &self.intervals {
+            let first = other
+                .intervals
+                .partition_point(|other| other.end < interval.start);
+            let overlapping = other.intervals[first..]
+                .iter()
+                .take_while(|other| other.start <= interval.end);
+
+            // Walk through the interval, keeping the gaps between what `other` takes out
+            let mut start = Some(interval.start);
+            for taken in overlapping {
+                let Some(gap_start) = start else { break };
+                if gap_start < taken.start {
+                    intervals.push(Interval::new(gap_start, taken.start - 1));
+                }
+                start = taken.end.checked_add(1);
+            }
+            if let Some(gap_start) = start.filter(|&gap_start| gap_start <= interval.end) {
+                intervals.push(Interval::new(gap_start, interval.end));
+            }
+        }
+        IntervalSet { intervals }
+    }
+
+    // The integers within `bounds` which aren't in the set
+    pub fn gaps_within(&self, bounds: Interval) -> IntervalSet {
+        IntervalSet::from(bounds).difference(self)
+    }
+}
+
+impl From<Interval> for IntervalSet {
+    fn from(interval: Interval) -> Self {
+        IntervalSet {
+            intervals: vec![interval],
+        }
+    }
+}
+
+impl Extend<Interval> for IntervalSet {
+    fn extend<I: IntoIterator<Item = Interval>>(&mut self, intervals: I) {
+        intervals
+            .into_iter()
+            .for_each(|interval| self.insert(interval));
+    }
+}
+
+impl FromIterator<Interval> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = Interval>>(intervals: I) -> Self {
+        let mut set = IntervalSet::new();
+        set.extend(intervals);
+        set
+    }
+}
+
+impl fmt::Display for IntervalSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{{{}}}", self.intervals.iter().join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set_of(intervals: &[(i64, i64)]) -> IntervalSet {
+        let intervals = intervals
+            .iter()
+            .map(|&(start, end)| Interval::new(start, end));
+        intervals.collect()
+    }
+
+    #[test]
+    fn combines_intervals() {
+        let (a, b) = (Interval::new(2, 6), Interval::new(4, 8));
+        assert_eq!((a.start(), a.end(), a.length()), (2, 6, 5));
+        assert_eq!(Interval::new(i64::MIN, i64::MAX).length(), 1 << 64);
+        assert!(a.overlaps(&b) && !a.contains_interval(&b));
+        assert!(Interval::new(1, 9).contains_interval(&a));
+        assert_eq!(a.intersection(&b), Some(Interval::new(4, 6)));
+        assert_eq!(a.union(&b), Some(Interval::new(2, 8)));
+        // Adjacent intervals have no gap between them, but don't overlap
+        let c = Interval::new(7, 9);
+        assert!(!a.overlaps(&c));
+        assert_eq!(a.union(&c), Some(Interval::new(2, 9)));
+        assert_eq!(a.union(&Interval::new(8, 9)), None);
+        assert_eq!(
+            Interval::new(1, 9).difference(&a),
+            set_of(&[(1, 1), (7, 9)])
+        );
+        assert!(a.difference(&Interval::new(0, 10)).is_empty());
+    }
+
+    #[test]
+    fn normalises_interval_sets() {
+        let set = set_of(&[(10, 12), (1, 3), (4, 5), (8, 8), (2, 4), (14, 20)]);
+        assert_eq!(set, set_of(&[(1, 5), (8, 8), (10, 12), (14, 20)]));
+        assert_eq!(set.len(), 5 + 1 + 3 + 7);
+        assert!(set.contains(11) && !set.contains(13) && !set.contains(0));
+        assert_eq!(set.to_string(), "{1-5, 8-8, 10-12, 14-20}");
+
+        let mut bridged = set.clone();
+        bridged.insert(Interval::new(6, 13));
+        assert_eq!(bridged, set_of(&[(1, 20)]));
+    }
+
+    #[test]
+    fn combines_interval_sets() {
+        let a = set_of(&[(1, 5), (10, 15)]);
+        let b = set_of(&[(3, 11), (14, 14), (20, 22)]);
+        assert_eq!(a.union(&b), set_of(&[(1, 15), (20, 22)]));
+        assert_eq!(a.intersection(&b), set_of(&[(3, 5), (10, 11), (14, 14)]));
+        assert_eq!(a.difference(&b), set_of(&[(1, 2), (12, 13), (15, 15)]));
+        assert_eq!(b.difference(&a), set_of(&[(6, 9), (20, 22)]));
+        assert_eq!(
+            a.gaps_within(Interval::new(0, 16)),
+            set_of(&[(0, 0), (6, 9), (16, 16)])
+        );
+        let everything = Interval::new(i64::MIN, i64::MAX);
+        assert!(IntervalSet::from(everything)
+            .difference(&a)
+            .contains(i64::MAX));
+    }
+}
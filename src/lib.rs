@@ -0,0 +1,17 @@
+// Each day lives in `days::day_XX`, exposing its parsed input types, its parser (through
+// `Solver`, which implements `solution::Solution`) and the functions solving each part.
+
+pub mod answers;
+pub mod days;
+pub mod fetch;
+pub mod interval;
+pub mod pool;
+pub mod scaffold;
+pub mod solution;
+#[cfg(test)]
+mod stub_server;
+pub mod submit;
+pub mod timing;
+pub mod top_k;
+pub mod utils;
+pub mod watch;
@@ -0,0 +1,538 @@
+use advent_2022::answers::{Answers, Verdict, DEFAULT_ANSWERS_PATH};
+use advent_2022::days;
+use advent_2022::pool::{map_in_parallel, panic_message};
+use advent_2022::scaffold;
+use advent_2022::solution::{Answer, Report, ReportOptions};
+use advent_2022::submit::{Outcome, Submitter, DEFAULT_LEDGER_PATH};
+use advent_2022::timing::{timed, Stats};
+use advent_2022::utils::{Error, InputSource};
+use advent_2022::watch::Watcher;
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use itertools::Itertools;
+use serde::Serialize;
+use std::fs;
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
+use std::process::{self, ExitCode};
+use std::str::FromStr;
+use std::thread;
+use std::time::Duration;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2022 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the solutions for the selected days
+    Run {
+        #[command(flatten)]
+        day_args: DayArgs,
+        /// Answers to verify against. Defaults to `answers.toml` when it exists, unless
+        /// another input is given.
+        #[arg(short, long, value_name = "PATH")]
+        answers: Option<PathBuf>,
+        /// Report how long parsing and each part took
+        #[arg(short, long)]
+        time: bool,
+        /// Defaults to a summary table when running several days, and to text otherwise
+        #[arg(short, long, value_enum)]
+        format: Option<Format>,
+        /// Number of days to run concurrently
+        #[arg(short, long, default_value_t = 1)]
+        jobs: usize,
+    },
+    /// Time repeated runs of parsing and of each part for the selected days
+    Bench {
+        #[command(flatten)]
+        day_args: DayArgs,
+        /// Number of runs to time
+        #[arg(short = 'n', long, default_value_t = 100)]
+        iterations: usize,
+    },
+    /// Print a report about a day's input, such as `aoc report 1 calories`
+    Report {
+        day: u8,
+        /// Name of the report, which can be left out when the day has only one
+        name: Option<String>,
+        /// Input file or directory of `day-XX.txt` inputs, as for `run`
+        #[arg(short, long, value_name = "PATH")]
+        input: Option<InputSource>,
+        /// An option of the report, such as `-o group-size=4` for day 3's reports
+        #[arg(short, long = "option", value_name = "NAME=VALUE", value_parser = parse_report_option)]
+        options: Vec<(String, String)>,
+    },
+    /// Solve a part and submit its answer, recording the outcome in a ledger. Answers known to
+    /// be wrong from the ledger aren't submitted again.
+    Submit {
+        day: u8,
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        /// Ledger of past submissions
+        #[arg(short, long, value_name = "PATH", default_value = DEFAULT_LEDGER_PATH)]
+        ledger: PathBuf,
+    },
+    /// Create the module of a new day, register it and add empty input and example files
+    New { day: u8 },
+    /// Re-run a day, with timing and verification, whenever its source or input changes
+    Watch {
+        day: u8,
+        /// Input file or directory of `day-XX.txt` inputs, as for `run`
+        #[arg(short, long, value_name = "PATH")]
+        input: Option<PathBuf>,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    Text,
+    /// A row per part with its status, time and answer
+    Table,
+    /// One JSON record per part, on a line of its own
+    Json,
+}
+
+#[derive(Serialize)]
+struct PartRecord<'a> {
+    day: u8,
+    part: usize,
+    answer: &'a Answer,
+    answer_type: &'static str,
+    verdict: String,
+    expected: Option<&'a Answer>,
+    parse_time_ns: u128,
+    time_ns: u128,
+}
+
+#[derive(Args)]
+struct DayArgs {
+    /// One day (`7`), an inclusive range of days (`1..10`) or `all`
+    days: DaySelection,
+    /// Input file, directory of `day-XX.txt` inputs, or `-` to read stdin. Defaults to
+    /// the `inputs` directory, or to $AOC_INPUT_DIR when set.
+    #[arg(short, long, value_name = "PATH")]
+    input: Option<InputSource>,
+}
+
+impl DayArgs {
+    fn resolve(self) -> Result<(Vec<&'static days::Day>, InputSource), Error> {
+        let days = self.days.days()?;
+        let source = self.input.unwrap_or(InputSource::Default);
+        if days.len() > 1 && source.is_single_day() {
+            let msg = "an input file or stdin can only be used to run a single day";
+            return Err(Error::Usage(String::from(msg)));
+        }
+        Ok((days, source))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum DaySelection {
+    All,
+    Range(RangeInclusive<u8>),
+}
+
+impl FromStr for DaySelection {
+    type Err = String;
+
+    fn from_str(selection: &str) -> Result<Self, Self::Err> {
+        let parse_day = |day_str: &str| {
+            day_str
+                .trim()
+                .parse::<u8>()
+                .map_err(|_| format!("invalid day `{}`", day_str))
+        };
+
+        if selection == "all" {
+            return Ok(DaySelection::All);
+        }
+        // Ranges are inclusive on both ends, so `1..10` includes day 10
+        match selection.split_once("..") {
+            Some((start, end)) => {
+                let end = end.strip_prefix('=').unwrap_or(end);
+                let (start, end) = (parse_day(start)?, parse_day(end)?);
+                match start <= end {
+                    true => Ok(DaySelection::Range(start..=end)),
+                    false => Err(format!("range `{}` ends before it starts", selection)),
+                }
+            }
+            None => {
+                let day = parse_day(selection)?;
+                Ok(DaySelection::Range(day..=day))
+            }
+        }
+    }
+}
+
+impl DaySelection {
+    fn days(&self) -> Result<Vec<&'static days::Day>, Error> {
+        match self {
+            DaySelection::All => Ok(days::DAYS.iter().collect()),
+            DaySelection::Range(range) => range
+                .clone()
+                .map(|number| days::find(number).ok_or(Error::UnsolvedDay(number)))
+                .collect(),
+        }
+    }
+}
+
+fn load_answers(path: Option<PathBuf>, source: &InputSource) -> Result<Answers, Error> {
+    match path {
+        Some(path) => Answers::load(&path),
+        // Stored answers are for our own inputs, so there's nothing to verify others against
+        None if *source != InputSource::Default => Ok(Answers::default()),
+        None if !Path::new(DEFAULT_ANSWERS_PATH).exists() => Ok(Answers::default()),
+        None => Answers::load(Path::new(DEFAULT_ANSWERS_PATH)),
+    }
+}
+
+fn print_part(columns: &str, answer: &Answer, verdict: &Verdict, time: Option<Duration>) {
+    let mut prefix = format!("{}{:9}", columns, verdict.to_string());
+    if let Some(time) = time {
+        prefix += &format!("{:12}", format!("{:.1?}", time));
+    }
+    // Keep the lines of multi-line answers (e.g. the CRT image of day 10) aligned
+    let width = prefix.chars().count();
+    let indent = |text: String| text.replace('\n', &format!("\n{:width$}", ""));
+
+    match verdict {
+        Verdict::Fail { expected } if !expected.to_string().contains('\n') => {
+            println!("{}{} (expected {})", prefix, answer, expected)
+        }
+        Verdict::Fail { expected } => {
+            println!("{}{}", prefix, indent(answer.to_string()));
+            println!("{:width$}{}", "expected", indent(expected.to_string()));
+        }
+        _ => println!("{}{}", prefix, indent(answer.to_string())),
+    }
+}
+
+fn print_day_text(day: u8, report: &Report, verdicts: &[Verdict; 2], time: bool) {
+    match time {
+        true => println!("Day {:02} (parsed in {:.1?})", day, report.parse_time),
+        false => println!("Day {:02}", day),
+    }
+
+    let parts = report.answers.iter().zip(verdicts).zip(report.part_times);
+    for ((answer, verdict), part_time) in parts {
+        print_part("", answer, verdict, Some(part_time).filter(|_| time));
+    }
+}
+
+fn print_table_header() {
+    println!("{:5}{:6}{:9}{:12}Answer", "Day", "Part", "Status", "Time");
+}
+
+fn print_day_table(day: u8, report: &Report, verdicts: &[Verdict; 2]) {
+    let parts = report.answers.iter().zip(verdicts).zip(report.part_times);
+    for (part_idx, ((answer, verdict), part_time)) in parts.enumerate() {
+        let columns = format!("{:<5}{:<6}", format!("{:02}", day), part_idx + 1);
+        print_part(&columns, answer, verdict, Some(part_time));
+    }
+}
+
+fn print_day_json(day: u8, report: &Report, verdicts: &[Verdict; 2]) {
+    for (part_idx, answer) in report.answers.iter().enumerate() {
+        let verdict = &verdicts[part_idx];
+        let record = PartRecord {
+            day,
+            part: part_idx + 1,
+            answer,
+            answer_type: answer.type_name(),
+            verdict: verdict.to_string(),
+            expected: match verdict {
+                Verdict::Fail { expected } => Some(expected),
+                _ => None,
+            },
+            parse_time_ns: report.parse_time.as_nanos(),
+            time_ns: report.part_times[part_idx].as_nanos(),
+        };
+        println!("{}", serde_json::to_string(&record).unwrap());
+    }
+}
+
+// Runs the selected days, returning whether every day ran and no answer failed verification.
+// Days that can't be run (e.g. for a missing input) don't stop the others.
+fn run(
+    day_args: DayArgs,
+    answers: Option<PathBuf>,
+    time: bool,
+    format: Option<Format>,
+    jobs: usize,
+) -> Result<bool, Error> {
+    if jobs == 0 {
+        return Err(Error::Usage(String::from("at least one job is needed")));
+    }
+    let (days, source) = day_args.resolve()?;
+    let expected_answers = load_answers(answers, &source)?;
+    let format = format.unwrap_or(match days.len() {
+        1 => Format::Text,
+        _ => Format::Table,
+    });
+
+    let (reports, total_time) = timed(|| map_in_parallel(&days, jobs, |day| (day.solve)(&source)));
+    if let Format::Table = format {
+        print_table_header();
+    }
+
+    let (mut num_failures, mut num_errors) = (0, 0);
+    for (day, result) in days.iter().zip(reports) {
+        // A day that panicked is reported like one that failed to run
+        let report = match result {
+            Ok(report) => report.map_err(|err| err.to_string()),
+            Err(panic) => Err(format!("panicked: {}", panic_message(&*panic))),
+        };
+        let report = match report {
+            Ok(report) => report,
+            Err(err) => {
+                num_errors += 1;
+                match format {
+                    Format::Table => {
+                        let columns = format!("{:<5}{:<6}", format!("{:02}", day.number), "-");
+                        println!("{}{:9}{:12}{}", columns, "error", "", err);
+                    }
+                    Format::Text | Format::Json => eprintln!("Day {:02}: {}", day.number, err),
+                }
+                continue;
+            }
+        };
+        let verdicts = expected_answers.verify(day.number, &report.answers);
+        match format {
+            Format::Text => print_day_text(day.number, &report, &verdicts, time),
+            Format::Table => print_day_table(day.number, &report, &verdicts),
+            Format::Json => print_day_json(day.number, &report, &verdicts),
+        }
+        num_failures += verdicts
+            .iter()
+            .filter(|verdict| matches!(verdict, Verdict::Fail { .. }))
+            .count();
+    }
+
+    if let Format::Table = format {
+        let num_threads = jobs.min(days.len());
+        println!(
+            "Ran {} day(s) in {:.1?} on {} thread(s)",
+            days.len(),
+            total_time,
+            num_threads
+        );
+    }
+    if num_failures > 0 {
+        eprintln!("{} answer(s) did not match", num_failures);
+    }
+    if num_errors > 0 {
+        eprintln!("{} day(s) could not be run", num_errors);
+    }
+    Ok(num_failures == 0 && num_errors == 0)
+}
+
+fn bench(day_args: DayArgs, iterations: usize) -> Result<(), Error> {
+    if iterations == 0 {
+        return Err(Error::Usage(String::from(
+            "at least one iteration is needed",
+        )));
+    }
+    let (days, source) = day_args.resolve()?;
+    let print_stats = |label: &str, stats: &Stats| {
+        println!(
+            "{:10}mean {:<12}median {:<12}stddev {:.1?}",
+            label,
+            format!("{:.1?}", stats.mean),
+            format!("{:.1?}", stats.median),
+            stats.stddev
+        )
+    };
+
+    for day in days {
+        let report = (day.bench)(&source, iterations)?;
+        println!("Day {:02} ({} iterations)", day.number, report.iterations);
+        print_stats("parse", &report.parse);
+        print_stats("part one", &report.parts[0]);
+        print_stats("part two", &report.parts[1]);
+    }
+    Ok(())
+}
+
+fn parse_report_option(option: &str) -> Result<(String, String), String> {
+    match option.split_once('=') {
+        Some((name, value)) => Ok((String::from(name), String::from(value))),
+        None => Err(format!("expected `NAME=VALUE`, found `{}`", option)),
+    }
+}
+
+fn report(
+    day: u8,
+    name: Option<String>,
+    input: Option<InputSource>,
+    options: ReportOptions,
+) -> Result<(), Error> {
+    let reports = days::reports_for(day).collect_vec();
+    let names = reports.iter().map(|report| report.name).join(", ");
+    let report = match (name, reports.as_slice()) {
+        (_, []) => return Err(Error::Usage(format!("no reports for day {}", day))),
+        (None, [report]) => report,
+        (Some(name), _) => reports
+            .iter()
+            .find(|report| report.name == name)
+            .ok_or_else(|| {
+                let msg = format!("no report `{}` for day {} (it has: {})", name, day, names);
+                Error::Usage(msg)
+            })?,
+        (None, _) => {
+            let msg = format!("day {} has several reports, pick one of {}", day, names);
+            return Err(Error::Usage(msg));
+        }
+    };
+
+    let source = input.unwrap_or(InputSource::Default);
+    print!("{}", (report.render)(&source, &options)?);
+    Ok(())
+}
+
+// Returns whether the answer was correct
+fn submit(day: u8, part: u8, ledger: PathBuf) -> Result<bool, Error> {
+    let submitter = Submitter::from_env()?;
+    let day_to_solve = days::find(day).ok_or(Error::UnsolvedDay(day))?;
+    let [part_one, part_two] = (day_to_solve.solve)(&InputSource::Default)?.answers;
+    let answer = match part {
+        1 => part_one,
+        _ => part_two,
+    };
+
+    println!("Submitting {} for day {:02} part {}", answer, day, part);
+    let response = submitter.submit(&ledger, day, part, answer)?;
+    match response.wait {
+        Some(wait) => println!(
+            "{} (wait {:?} before submitting again)",
+            response.outcome, wait
+        ),
+        None => println!("{}", response.outcome),
+    }
+    Ok(response.outcome == Outcome::Correct)
+}
+
+// Runs the day through `cargo run`, so that changes to its source are rebuilt first
+fn run_with_cargo(day: u8, input: Option<&PathBuf>) {
+    let mut cargo = process::Command::new("cargo");
+    cargo.args([
+        "run",
+        "--bin",
+        "aoc",
+        "--",
+        "run",
+        &day.to_string(),
+        "--time",
+    ]);
+    if let Some(input) = input {
+        cargo.arg("--input").arg(input);
+    }
+    match cargo.status() {
+        Ok(status) if !status.success() => eprintln!("Day {:02} exited with {}", day, status),
+        Ok(_) => {}
+        Err(err) => eprintln!("Couldn't run cargo: {}", err),
+    }
+}
+
+fn watch(day: u8, input: Option<PathBuf>) -> Result<(), Error> {
+    let source = input
+        .clone()
+        .map_or(InputSource::Default, InputSource::Path);
+    let input_path = source.path(day).unwrap();
+    // The day's module and its submodules, along with the sources shared between days
+    let day_module = format!("src/days/day_{:02}", day);
+    let mut paths = vec![
+        PathBuf::from(format!("{}.rs", day_module)),
+        PathBuf::from(day_module),
+        PathBuf::from("src/days/mod.rs"),
+    ];
+    let shared_sources = fs::read_dir("src").map_err(|source| Error::Io {
+        path: PathBuf::from("src"),
+        source,
+    })?;
+    let shared_sources = shared_sources.filter_map(|entry| entry.ok().map(|entry| entry.path()));
+    let shared_sources =
+        shared_sources.filter(|path| path.extension().is_some_and(|ext| ext == "rs"));
+    paths.extend(shared_sources.sorted());
+    paths.push(input_path);
+    let mut watcher = Watcher::new(paths);
+
+    let watched = watcher.paths().iter().map(|path| path.display()).join(", ");
+    println!("Watching {}", watched);
+    run_with_cargo(day, input.as_ref());
+    loop {
+        thread::sleep(Duration::from_millis(500));
+        let changed = watcher.poll();
+        if !changed.is_empty() {
+            let changed = changed.iter().map(|path| path.display()).join(", ");
+            println!("\n{} changed, re-running day {:02}", changed, day);
+            run_with_cargo(day, input.as_ref());
+        }
+    }
+}
+
+fn main() -> Result<ExitCode, Error> {
+    let success = match Cli::parse().command {
+        Command::Run {
+            day_args,
+            answers,
+            time,
+            format,
+            jobs,
+        } => run(day_args, answers, time, format, jobs)?,
+        Command::Bench {
+            day_args,
+            iterations,
+        } => {
+            bench(day_args, iterations)?;
+            true
+        }
+        Command::Report {
+            day,
+            name,
+            input,
+            options,
+        } => {
+            report(day, name, input, ReportOptions(options))?;
+            true
+        }
+        Command::Submit { day, part, ledger } => submit(day, part, ledger)?,
+        Command::New { day } => {
+            for path in scaffold::new_day(Path::new("."), day)? {
+                println!("Created {}", path.display());
+            }
+            true
+        }
+        Command::Watch { day, input } => {
+            watch(day, input)?;
+            true
+        }
+    };
+
+    match success {
+        true => Ok(ExitCode::SUCCESS),
+        false => Ok(ExitCode::FAILURE),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_day_selections() {
+        let parse = |selection: &str| selection.parse::<DaySelection>();
+        assert_eq!(parse("7"), Ok(DaySelection::Range(7..=7)));
+        assert_eq!(parse("1..10"), Ok(DaySelection::Range(1..=10)));
+        assert_eq!(parse("1..=10"), Ok(DaySelection::Range(1..=10)));
+        assert_eq!(parse("all"), Ok(DaySelection::All));
+        assert_eq!(
+            parse("10..1"),
+            Err(String::from("range `10..1` ends before it starts"))
+        );
+        assert_eq!(parse("1..x"), Err(String::from("invalid day `x`")));
+        assert!(parse("seven").is_err());
+    }
+}
@@ -0,0 +1,85 @@
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+
+// Maps `func` over `items` on `jobs` threads, keeping the results in the order of the items.
+// Each thread takes the next unclaimed item as soon as it's done with its previous one, so
+// slow items don't hold up the rest. An item for which `func` panics gets the panic as its
+// result, and the other items are still mapped.
+pub fn map_in_parallel<T: Sync, R: Send>(
+    items: &[T],
+    jobs: usize,
+    func: impl Fn(&T) -> R + Sync,
+) -> Vec<thread::Result<R>> {
+    assert!(jobs > 0, "at least one job is needed");
+    let next_idx = AtomicUsize::new(0);
+    let worker = || {
+        let mut results = Vec::new();
+        loop {
+            let idx = next_idx.fetch_add(1, Ordering::Relaxed);
+            match items.get(idx) {
+                Some(item) => {
+                    let result = panic::catch_unwind(AssertUnwindSafe(|| func(item)));
+                    results.push((idx, result));
+                }
+                None => return results,
+            }
+        }
+    };
+
+    let mut results: Vec<(usize, thread::Result<R>)> = thread::scope(|scope| {
+        let workers: Vec<_> = (0..jobs.min(items.len()))
+            .map(|_| scope.spawn(worker))
+            .collect();
+        workers
+            .into_iter()
+            .flat_map(|handle| handle.join().unwrap())
+            .collect()
+    });
+    results.sort_by_key(|(idx, _)| *idx);
+    results.into_iter().map(|(_, result)| result).collect()
+}
+
+// The message a panic was raised with, when it has one
+pub fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    match (
+        payload.downcast_ref::<&str>(),
+        payload.downcast_ref::<String>(),
+    ) {
+        (Some(message), _) => message,
+        (_, Some(message)) => message,
+        (None, None) => "unknown panic",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn keeps_results_in_order() {
+        let items: Vec<u64> = (0..20).collect();
+        let results = map_in_parallel(&items, 4, |item| {
+            // Finish the earlier items last
+            thread::sleep(Duration::from_millis(20 - item));
+            item * 2
+        });
+        let results = results.into_iter().map(Result::unwrap).collect::<Vec<_>>();
+        assert_eq!(results, (0..20).map(|item| item * 2).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn keeps_going_after_a_panic() {
+        let items: Vec<u64> = (0..10).collect();
+        let results = map_in_parallel(&items, 2, |&item| match item {
+            3 => panic!("item {} is unlucky", item),
+            _ => item,
+        });
+        let panic = results[3].as_ref().unwrap_err();
+        assert_eq!(panic_message(&**panic), "item 3 is unlucky");
+        let mapped = results.iter().filter_map(|result| result.as_ref().ok());
+        assert_eq!(mapped.count(), 9);
+    }
+}
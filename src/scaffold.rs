@@ -0,0 +1,166 @@
+// Creates the module of a new day from `templates/day.rs` and registers it in `src/days/mod.rs`,
+// along with empty input and example files.
+
+use crate::utils::Error;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const TEMPLATE: &str = include_str!("../templates/day.rs");
+
+// Returns the files that were created, relative to `root`
+pub fn new_day(root: &Path, day: u8) -> Result<Vec<PathBuf>, Error> {
+    if !(1..=25).contains(&day) {
+        return Err(Error::Usage(format!("there's no day {} to scaffold", day)));
+    }
+    let module_path = PathBuf::from(format!("src/days/day_{:02}.rs", day));
+    if root.join(&module_path).exists() {
+        let msg = format!("day {} already exists at {}", day, module_path.display());
+        return Err(Error::Usage(msg));
+    }
+
+    let registry_path = root.join("src/days/mod.rs");
+    let registry = fs::read_to_string(&registry_path).map_err(|err| Error::Io {
+        path: registry_path.clone(),
+        source: err,
+    })?;
+    let registry = register(&registry, day).map_err(Error::Usage)?;
+
+    let mut created = vec![module_path.clone()];
+    write_file(
+        root,
+        &module_path,
+        &TEMPLATE.replace("{{DAY}}", &day.to_string()),
+    )?;
+    write_file(root, Path::new("src/days/mod.rs"), &registry)?;
+    // Inputs may already be there (e.g. fetched), so only add the missing ones
+    for input_dir in ["inputs", "inputs/examples"] {
+        let input_path = Path::new(input_dir).join(format!("day-{:02}.txt", day));
+        if !root.join(&input_path).exists() {
+            write_file(root, &input_path, "")?;
+            created.push(input_path);
+        }
+    }
+    Ok(created)
+}
+
+fn write_file(root: &Path, path: &Path, contents: &str) -> Result<(), Error> {
+    let path = root.join(path);
+    let io_error = |source| Error::Io {
+        path: path.clone(),
+        source,
+    };
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(io_error)?;
+    }
+    fs::write(&path, contents).map_err(io_error)
+}
+
+// Adds the day's module and its entry in `DAYS` to the registry, keeping both in day order
+fn register(registry: &str, day: u8) -> Result<String, String> {
+    let module = format!("day_{:02}", day);
+    let mod_line = format!("pub mod {};", module);
+    let entry_line = format!("    Day::of::<{}::Solver>(),", module);
+    if registry.lines().any(|line| line == mod_line) {
+        return Err(format!("day {} is already registered", day));
+    }
+
+    let mut lines: Vec<String> = registry.lines().map(String::from).collect();
+    insert_sorted(&mut lines, mod_line, |line| {
+        line.starts_with("pub mod day_")
+    })
+    .ok_or("expected `pub mod day_XX;` lines in the registry")?;
+    insert_sorted(&mut lines, entry_line, |line| {
+        line.starts_with("    Day::of::<day_")
+    })
+    .ok_or("expected `Day::of::<day_XX::Solver>()` entries in the registry")?;
+
+    // The length of `DAYS` is part of its type
+    let days_line = lines
+        .iter_mut()
+        .find(|line| line.starts_with("pub static DAYS: [Day; "))
+        .ok_or("expected `pub static DAYS: [Day; N]` in the registry")?;
+    let num_days = days_line
+        .trim_start_matches("pub static DAYS: [Day; ")
+        .split(']')
+        .next()
+        .and_then(|num_days| num_days.parse::<usize>().ok())
+        .ok_or("expected the number of days in `DAYS`")?;
+    *days_line = format!("pub static DAYS: [Day; {}] = [", num_days + 1);
+
+    Ok(lines.join("\n") + "\n")
+}
+
+// Inserts `new_line` among the consecutive lines matching `is_block_line`, in sorted order
+fn insert_sorted(
+    lines: &mut Vec<String>,
+    new_line: String,
+    is_block_line: impl Fn(&str) -> bool,
+) -> Option<()> {
+    let block_start = lines.iter().position(|line| is_block_line(line))?;
+    let block_len = lines[block_start..]
+        .iter()
+        .take_while(|line| is_block_line(line))
+        .count();
+    let block = &lines[block_start..block_start + block_len];
+    let offset = block.partition_point(|line| *line < new_line);
+    lines.insert(block_start + offset, new_line);
+    Some(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REGISTRY: &str = "\
+pub mod day_01;
+pub mod day_12;
+
+// Registry of every solved day, in calendar order
+pub static DAYS: [Day; 2] = [
+    Day::of::<day_01::Solver>(),
+    Day::of::<day_12::Solver>(),
+];
+";
+
+    #[test]
+    fn registers_days_in_order() {
+        let registry = register(REGISTRY, 7).unwrap();
+        let expected = "\
+pub mod day_01;
+pub mod day_07;
+pub mod day_12;
+
+// Registry of every solved day, in calendar order
+pub static DAYS: [Day; 3] = [
+    Day::of::<day_01::Solver>(),
+    Day::of::<day_07::Solver>(),
+    Day::of::<day_12::Solver>(),
+];
+";
+        assert_eq!(registry, expected);
+        assert!(register(&registry, 7).is_err());
+    }
+
+    #[test]
+    fn scaffolds_a_new_day() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        write_file(&root, Path::new("src/days/mod.rs"), REGISTRY).unwrap();
+
+        let created = new_day(&root, 7).unwrap();
+        assert_eq!(
+            created,
+            [
+                "src/days/day_07.rs",
+                "inputs/day-07.txt",
+                "inputs/examples/day-07.txt"
+            ]
+            .map(PathBuf::from)
+        );
+        let module = fs::read_to_string(root.join("src/days/day_07.rs")).unwrap();
+        assert!(module.starts_with("// https://adventofcode.com/2022/day/7\n"));
+        assert!(module.contains("const DAY: u8 = 7;"));
+        assert!(new_day(&root, 7).is_err());
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,205 @@
+use crate::timing::{timed, Stats};
+use crate::utils::{lines_from_reader, Error, InputSource};
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::io::BufRead;
+use std::path::Path;
+use std::str::FromStr;
+use std::time::Duration;
+
+// The answer to one part of a puzzle. Puzzles either ask for a number or for some text
+// (e.g. the crates on top of each stack in day 5 or the CRT image in day 10).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+}
+
+impl Answer {
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Answer::Number(_) => "number",
+            Answer::Text(_) => "text",
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(num) => write!(f, "{}", num),
+            Answer::Text(text) => write!(f, "{}", text),
+        }
+    }
+}
+
+macro_rules! impl_answer_from_number {
+    ($($num_type:ty),*) => {
+        $(
+            impl From<$num_type> for Answer {
+                fn from(num: $num_type) -> Self {
+                    Answer::Number(num as i64)
+                }
+            }
+        )*
+    };
+}
+
+impl_answer_from_number!(i32, i64, u32, usize);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(String::from(text))
+    }
+}
+
+// Every day parses its input once and then answers both parts from the parsed input.
+pub trait Solution {
+    const DAY: u8;
+    type Input;
+
+    fn parse(lines: impl Iterator<Item = String>) -> Result<Self::Input, Error>;
+    fn part_one(input: &Self::Input) -> Answer;
+    fn part_two(input: &Self::Input) -> Answer;
+}
+
+// Options given to a report on the command line, as `name=value`
+#[derive(Debug, Clone, Default)]
+pub struct ReportOptions(pub Vec<(String, String)>);
+
+impl ReportOptions {
+    // The value of the option, when given (the last time, if given more than once)
+    pub fn get<T: FromStr>(&self, name: &str) -> Result<Option<T>, Error>
+    where
+        T::Err: fmt::Display,
+    {
+        let value = self.0.iter().rev().find(|(option, _)| option == name);
+        value
+            .map(|(_, value)| {
+                value.parse().map_err(|err| {
+                    let msg = format!("invalid value `{}` for `{}`: {}", value, name, err);
+                    Error::Usage(msg)
+                })
+            })
+            .transpose()
+    }
+}
+
+// A report about a day's input, beyond the answers to its parts (e.g. statistics of it)
+pub trait InputReport: fmt::Display + Sized {
+    type Solver: Solution;
+    const NAME: &'static str;
+    const ABOUT: &'static str;
+    // Names of the options the report takes
+    const OPTIONS: &'static [&'static str] = &[];
+
+    // Reports may accept inputs which the solver rejects, so as to point out what's wrong
+    fn parse(
+        lines: impl Iterator<Item = String>,
+    ) -> Result<<Self::Solver as Solution>::Input, Error> {
+        Self::Solver::parse(lines)
+    }
+
+    fn of(input: &<Self::Solver as Solution>::Input) -> Self;
+
+    fn with_options(
+        input: &<Self::Solver as Solution>::Input,
+        _options: &ReportOptions,
+    ) -> Result<Self, Error> {
+        Ok(Self::of(input))
+    }
+}
+
+pub fn render_report<R: InputReport>(
+    source: &InputSource,
+    options: &ReportOptions,
+) -> Result<String, Error> {
+    if let Some((name, _)) = options
+        .0
+        .iter()
+        .find(|(name, _)| !R::OPTIONS.contains(&&**name))
+    {
+        let msg = match R::OPTIONS {
+            [] => format!("report `{}` takes no options", R::NAME),
+            known => format!(
+                "unknown option `{}` (expected one of {})",
+                name,
+                known.join(", ")
+            ),
+        };
+        return Err(Error::Usage(msg));
+    }
+    let lines = source.lines(R::Solver::DAY)?;
+    let input = R::parse(lines)?;
+    Ok(R::with_options(&input, options)?.to_string())
+}
+
+pub struct Report {
+    pub answers: [Answer; 2],
+    pub parse_time: Duration,
+    pub part_times: [Duration; 2],
+}
+
+pub fn solve<S: Solution>(source: &InputSource) -> Result<Report, Error> {
+    solve_lines::<S>(source.lines(S::DAY)?)
+}
+
+// Solves the input read from `reader`, such as an open file or a network stream
+pub fn solve_reader<S: Solution>(reader: impl BufRead) -> Result<Report, Error> {
+    let lines = lines_from_reader(reader, Path::new("<reader>"))?;
+    solve_lines::<S>(lines.into_iter())
+}
+
+fn solve_lines<S: Solution>(lines: impl Iterator<Item = String>) -> Result<Report, Error> {
+    let (input, parse_time) = timed(|| S::parse(lines));
+    let input = input?;
+    let (part_one, part_one_time) = timed(|| S::part_one(&input));
+    let (part_two, part_two_time) = timed(|| S::part_two(&input));
+
+    Ok(Report {
+        answers: [part_one, part_two],
+        parse_time,
+        part_times: [part_one_time, part_two_time],
+    })
+}
+
+pub struct BenchReport {
+    pub iterations: usize,
+    pub parse: Stats,
+    pub parts: [Stats; 2],
+}
+
+// Times `iterations` runs of parsing and of each part. Reading the input is left out, as
+// it's only done once.
+pub fn bench<S: Solution>(source: &InputSource, iterations: usize) -> Result<BenchReport, Error> {
+    let lines = source.lines(S::DAY)?.collect_vec();
+    let mut parse_times = Vec::with_capacity(iterations);
+    let mut part_times = [
+        Vec::with_capacity(iterations),
+        Vec::with_capacity(iterations),
+    ];
+
+    for _ in 0..iterations {
+        let lines = lines.clone().into_iter();
+        let (input, parse_time) = timed(|| S::parse(lines));
+        parse_times.push(parse_time);
+
+        let input = input?;
+        part_times[0].push(timed(|| S::part_one(&input)).1);
+        part_times[1].push(timed(|| S::part_two(&input)).1);
+    }
+
+    Ok(BenchReport {
+        iterations,
+        parse: Stats::of(&parse_times),
+        parts: part_times.map(|times| Stats::of(&times)),
+    })
+}
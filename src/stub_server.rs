@@ -0,0 +1,51 @@
+// A local HTTP server for testing the Advent of Code client without going over the network
+
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::thread::{self, JoinHandle};
+
+// Serves a single request with `status` and `body`. Returns the base URL to send the request
+// to, and a handle joining to the request that was received (its head, a blank line and its
+// body).
+pub fn serve_once(status: &'static str, body: &'static str) -> (String, JoinHandle<String>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    let server = thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+        let mut reader = BufReader::new(&stream);
+        let mut request_head = Vec::new();
+        loop {
+            let mut line = String::new();
+            reader.read_line(&mut line).unwrap();
+            match line.trim_end() {
+                "" => break,
+                line => request_head.push(line.to_string()),
+            }
+        }
+
+        let content_length = request_head
+            .iter()
+            .find_map(|line| {
+                line.to_lowercase()
+                    .strip_prefix("content-length: ")
+                    .map(String::from)
+            })
+            .map_or(0, |len| len.parse().unwrap());
+        let mut request_body = vec![0; content_length];
+        reader.read_exact(&mut request_body).unwrap();
+
+        let response = format!(
+            "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            status,
+            body.len(),
+            body
+        );
+        stream.write_all(response.as_bytes()).unwrap();
+        format!(
+            "{}\n\n{}",
+            request_head.join("\n"),
+            String::from_utf8(request_body).unwrap()
+        )
+    });
+    (base_url, server)
+}
@@ -0,0 +1,387 @@
+// Submits answers to Advent of Code, keeping a ledger of every submission and its outcome. The
+// ledger is checked before submitting so that answers which are known to be wrong (or which are
+// beyond an answer that was too high or too low) are never sent again.
+
+use crate::fetch::{Client, SESSION_VAR};
+use crate::solution::Answer;
+use crate::utils::Error;
+use log::info;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::Path;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+pub const DEFAULT_LEDGER_PATH: &str = "submissions.json";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    // Wrong, without a hint of which way
+    Incorrect,
+    // Submitted too soon after the previous answer, so it wasn't checked
+    Wait,
+    // The part is already solved, or isn't unlocked yet
+    WrongLevel,
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "correct"),
+            Outcome::TooHigh => write!(f, "too high"),
+            Outcome::TooLow => write!(f, "too low"),
+            Outcome::Incorrect => write!(f, "incorrect"),
+            Outcome::Wait => write!(f, "wait"),
+            Outcome::WrongLevel => write!(f, "wrong level"),
+        }
+    }
+}
+
+impl Outcome {
+    fn is_wrong(&self) -> bool {
+        matches!(
+            self,
+            Outcome::TooHigh | Outcome::TooLow | Outcome::Incorrect
+        )
+    }
+}
+
+// What Advent of Code made of a submission, from the article of its response page
+#[derive(Debug, PartialEq, Eq)]
+pub struct Response {
+    pub outcome: Outcome,
+    pub wait: Option<Duration>,
+}
+
+impl Response {
+    pub fn parse(page: &str) -> Option<Self> {
+        let outcome = if page.contains("That's the right answer") {
+            Outcome::Correct
+        } else if page.contains("your answer is too high") {
+            Outcome::TooHigh
+        } else if page.contains("your answer is too low") {
+            Outcome::TooLow
+        } else if page.contains("That's not the right answer") {
+            Outcome::Incorrect
+        } else if page.contains("You gave an answer too recently") {
+            Outcome::Wait
+        } else if page.contains("You don't seem to be solving the right level") {
+            Outcome::WrongLevel
+        } else {
+            return None;
+        };
+        Some(Response {
+            outcome,
+            wait: parse_wait(page),
+        })
+    }
+}
+
+// Parses waits such as "You have 1m 5s left to wait" or "please wait 5 minutes before trying
+// again"
+fn parse_wait(page: &str) -> Option<Duration> {
+    let left_to_wait = |page: &str| {
+        let (before, _) = page.split_once(" left to wait")?;
+        let (_, wait) = before.rsplit_once("You have ")?;
+        wait.split_whitespace().try_fold(0, |secs, part| {
+            let (num, unit) = part.split_at(part.find(|chr: char| !chr.is_ascii_digit())?);
+            let num = num.parse::<u64>().ok()?;
+            match unit {
+                "s" => Some(secs + num),
+                "m" => Some(secs + num * 60),
+                "h" => Some(secs + num * 60 * 60),
+                _ => None,
+            }
+        })
+    };
+    let wait_before_retrying = |page: &str| {
+        let page = page.to_lowercase();
+        let (_, after) = page.split_once("please wait ")?;
+        let (wait, _) = after.split_once(" before trying again")?;
+        let (num, _) = wait.split_once(' ')?;
+        let minutes = match num {
+            "one" => 1,
+            num => num.parse::<u64>().ok()?,
+        };
+        Some(minutes * 60)
+    };
+    left_to_wait(page)
+        .or_else(|| wait_before_retrying(page))
+        .map(Duration::from_secs)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Submission {
+    pub day: u8,
+    pub part: u8,
+    pub answer: Answer,
+    pub outcome: Outcome,
+    // Seconds since the Unix epoch
+    pub submitted_at: u64,
+    // Until when no other answer should be submitted, in seconds since the Unix epoch
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retry_after: Option<u64>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Ledger {
+    pub submissions: Vec<Submission>,
+}
+
+impl Ledger {
+    // A missing ledger is an empty one
+    pub fn load(path: &Path) -> Result<Self, Error> {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(Ledger::default()),
+            Err(err) => {
+                return Err(Error::Io {
+                    path: path.to_path_buf(),
+                    source: err,
+                })
+            }
+        };
+        serde_json::from_str(&contents).map_err(|err| Error::InvalidLedger {
+            path: path.to_path_buf(),
+            message: err.to_string(),
+        })
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), Error> {
+        let contents = serde_json::to_string_pretty(self).unwrap() + "\n";
+        fs::write(path, contents).map_err(|err| Error::Io {
+            path: path.to_path_buf(),
+            source: err,
+        })
+    }
+
+    fn for_part(&self, day: u8, part: u8) -> impl Iterator<Item = &Submission> {
+        let is_part = move |sub: &&Submission| sub.day == day && sub.part == part;
+        self.submissions.iter().filter(is_part)
+    }
+
+    // Why `answer` shouldn't be submitted (as of `now`), if it shouldn't
+    pub fn check(&self, day: u8, part: u8, answer: &Answer, now: u64) -> Result<(), String> {
+        if let Some(retry_after) = self
+            .submissions
+            .iter()
+            .filter_map(|sub| sub.retry_after)
+            .max()
+        {
+            if retry_after > now {
+                return Err(format!(
+                    "wait {}s before submitting again",
+                    retry_after - now
+                ));
+            }
+        }
+
+        for sub in self.for_part(day, part) {
+            match (&sub.outcome, &sub.answer, answer) {
+                (Outcome::Correct, correct, _) => {
+                    return Err(format!("already solved, the answer was {}", correct))
+                }
+                (outcome, wrong, _) if outcome.is_wrong() && wrong == answer => {
+                    return Err(format!(
+                        "{} was already submitted and was {}",
+                        answer, outcome
+                    ))
+                }
+                (Outcome::TooHigh, Answer::Number(high), Answer::Number(num)) if num > high => {
+                    return Err(format!("{} is above {}, which was too high", num, high))
+                }
+                (Outcome::TooLow, Answer::Number(low), Answer::Number(num)) if num < low => {
+                    return Err(format!("{} is below {}, which was too low", num, low))
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    pub fn record(&mut self, day: u8, part: u8, answer: Answer, response: &Response, now: u64) {
+        self.submissions.push(Submission {
+            day,
+            part,
+            answer,
+            outcome: response.outcome,
+            submitted_at: now,
+            retry_after: response.wait.map(|wait| now + wait.as_secs()),
+        });
+    }
+}
+
+pub struct Submitter {
+    client: Client,
+}
+
+impl Submitter {
+    pub fn new(base_url: impl Into<String>, session: impl Into<String>) -> Self {
+        Submitter::with_client(Client::new(base_url, session))
+    }
+
+    pub fn with_client(client: Client) -> Self {
+        Submitter { client }
+    }
+
+    pub fn from_env() -> Result<Self, Error> {
+        let client = Client::from_env()
+            .ok_or_else(|| Error::Usage(format!("set ${} to submit answers", SESSION_VAR)))?;
+        Ok(Submitter::with_client(client))
+    }
+
+    pub fn url(&self, day: u8) -> String {
+        self.client.url(day, "answer")
+    }
+
+    // Checks the answer against the ledger at `ledger_path`, then submits it and records the
+    // response there
+    pub fn submit(
+        &self,
+        ledger_path: &Path,
+        day: u8,
+        part: u8,
+        answer: Answer,
+    ) -> Result<Response, Error> {
+        let submit_error = |message: String| Error::Submit { day, part, message };
+        if answer.to_string().contains('\n') {
+            let msg = "multi-line answers have to be read and submitted by hand";
+            return Err(submit_error(String::from(msg)));
+        }
+        let mut ledger = Ledger::load(ledger_path)?;
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        ledger
+            .check(day, part, &answer, now)
+            .map_err(submit_error)?;
+
+        let response = self.post(day, part, &answer).map_err(submit_error)?;
+        ledger.record(day, part, answer, &response, now);
+        ledger.save(ledger_path)?;
+        Ok(response)
+    }
+
+    fn post(&self, day: u8, part: u8, answer: &Answer) -> Result<Response, String> {
+        let url = self.url(day);
+        info!("Submitting {} to {}", answer, url);
+        let form = [("level", part.to_string()), ("answer", answer.to_string())];
+        let page = self
+            .client
+            .post_form(&url, form)
+            .map_err(|err| err.to_string())?;
+        Response::parse(&page).ok_or_else(|| String::from("unrecognised response"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stub_server::serve_once;
+
+    fn ledger(submissions: &[(u8, Answer, Outcome)]) -> Ledger {
+        let submissions = submissions
+            .iter()
+            .map(|(part, answer, outcome)| Submission {
+                day: 7,
+                part: *part,
+                answer: answer.clone(),
+                outcome: *outcome,
+                submitted_at: 0,
+                retry_after: None,
+            });
+        Ledger {
+            submissions: submissions.collect(),
+        }
+    }
+
+    #[test]
+    fn parses_responses() {
+        let too_high = "<article><p>That's not the right answer; your answer is too high. \
+            Please wait one minute before trying again.</p></article>";
+        let too_soon = "<article><p>You gave an answer too recently. You have 1m 5s left to \
+            wait.</p></article>";
+        assert_eq!(
+            Response::parse(too_high),
+            Some(Response {
+                outcome: Outcome::TooHigh,
+                wait: Some(Duration::from_secs(60))
+            })
+        );
+        assert_eq!(
+            Response::parse(too_soon),
+            Some(Response {
+                outcome: Outcome::Wait,
+                wait: Some(Duration::from_secs(65))
+            })
+        );
+        assert_eq!(
+            Response::parse("<html>Puzzle inputs differ by user.</html>"),
+            None
+        );
+    }
+
+    #[test]
+    fn refuses_known_wrong_and_out_of_bounds_answers() {
+        let ledger = ledger(&[
+            (1, Answer::from(100), Outcome::TooHigh),
+            (1, Answer::from(10), Outcome::TooLow),
+            (1, Answer::from(50), Outcome::Incorrect),
+            (2, Answer::from(3), Outcome::Correct),
+        ]);
+        let check = |part, answer| ledger.check(7, part, &Answer::from(answer), 0);
+
+        assert!(check(1, 50).is_err());
+        assert!(check(1, 100).is_err());
+        assert!(check(1, 101).is_err());
+        assert!(check(1, 9).is_err());
+        assert!(check(1, 42).is_ok());
+        assert!(check(2, 3).is_err());
+        assert!(ledger.check(8, 1, &Answer::from(100), 0).is_ok());
+    }
+
+    #[test]
+    fn refuses_answers_while_waiting() {
+        let mut ledger = ledger(&[]);
+        let response = Response {
+            outcome: Outcome::Wait,
+            wait: Some(Duration::from_secs(30)),
+        };
+        ledger.record(7, 1, Answer::from(42), &response, 1000);
+        assert!(ledger.check(7, 1, &Answer::from(42), 1029).is_err());
+        assert!(ledger.check(7, 1, &Answer::from(42), 1030).is_ok());
+    }
+
+    #[test]
+    fn submits_and_records_answers() {
+        let page = "<article><p>That's not the right answer; your answer is too low.</p></article>";
+        let (base_url, server) = serve_once("200 OK", page);
+        let ledger_path =
+            std::env::temp_dir().join(format!("aoc-ledger-{}.json", std::process::id()));
+        let _ = fs::remove_file(&ledger_path);
+        let submitter = Submitter::new(base_url, "abc123");
+
+        let response = submitter
+            .submit(&ledger_path, 7, 2, Answer::from(42))
+            .unwrap();
+        let request = server.join().unwrap();
+        assert_eq!(response.outcome, Outcome::TooLow);
+        assert!(request.starts_with("POST /2022/day/7/answer HTTP/1.1"));
+        assert!(request.contains("session=abc123"));
+        assert!(request.contains("user-agent: advent-2022/"));
+        assert!(request.ends_with("\n\nlevel=2&answer=42"));
+
+        // The server is gone, so this would fail if it were submitted
+        let resubmitted = submitter.submit(&ledger_path, 7, 2, Answer::from(41));
+        assert!(matches!(resubmitted, Err(Error::Submit { .. })));
+        let ledger = Ledger::load(&ledger_path).unwrap();
+        assert_eq!(ledger.submissions.len(), 1);
+        fs::remove_file(&ledger_path).unwrap();
+    }
+}
@@ -0,0 +1,52 @@
+use std::time::{Duration, Instant};
+
+pub fn timed<T>(func: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let result = func();
+    (result, start.elapsed())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub mean: Duration,
+    pub median: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn of(samples: &[Duration]) -> Self {
+        assert!(!samples.is_empty(), "no samples to compute stats of");
+        let mut sorted_samples = samples.to_vec();
+        sorted_samples.sort();
+
+        let mid = sorted_samples.len() / 2;
+        let median = match sorted_samples.len() % 2 {
+            0 => (sorted_samples[mid - 1] + sorted_samples[mid]) / 2,
+            _ => sorted_samples[mid],
+        };
+
+        let secs = samples.iter().map(Duration::as_secs_f64);
+        let mean = secs.clone().sum::<f64>() / samples.len() as f64;
+        let variance = secs.map(|sec| (sec - mean).powi(2)).sum::<f64>() / samples.len() as f64;
+
+        Stats {
+            mean: Duration::from_secs_f64(mean),
+            median,
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn computes_stats_of_samples() {
+        let samples = [2, 4, 4, 4, 5, 5, 7, 9].map(Duration::from_millis);
+        let stats = Stats::of(&samples);
+        assert_eq!(stats.mean, Duration::from_millis(5));
+        assert_eq!(stats.median, Duration::from_micros(4500));
+        assert_eq!(stats.stddev, Duration::from_millis(2));
+    }
+}
@@ -0,0 +1,62 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+// Collects the `k` largest of the values pushed into it, without holding on to the rest
+pub struct TopK<T> {
+    k: usize,
+    // A min-heap, so that the smallest of the top values is the one to evict
+    heap: BinaryHeap<Reverse<T>>,
+}
+
+impl<T: Ord> TopK<T> {
+    pub fn new(k: usize) -> Self {
+        TopK {
+            k,
+            heap: BinaryHeap::with_capacity(k + 1),
+        }
+    }
+
+    pub fn push(&mut self, val: T) {
+        match self.heap.peek() {
+            _ if self.heap.len() < self.k => self.heap.push(Reverse(val)),
+            Some(Reverse(smallest)) if *smallest < val => {
+                self.heap.pop();
+                self.heap.push(Reverse(val));
+            }
+            _ => {}
+        }
+    }
+
+    // The top values, largest first
+    pub fn into_sorted_vec(self) -> Vec<T> {
+        // Sorting the reversed values in ascending order puts the largest values first
+        let sorted = self.heap.into_sorted_vec();
+        sorted.into_iter().map(|Reverse(val)| val).collect()
+    }
+}
+
+impl<T: Ord> Extend<T> for TopK<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, vals: I) {
+        vals.into_iter().for_each(|val| self.push(val));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_the_largest_values() {
+        let mut top_three = TopK::new(3);
+        top_three.extend([5, 1, 9, 3, 9, 7, 2]);
+        assert_eq!(top_three.into_sorted_vec(), [9, 9, 7]);
+
+        let mut top_none = TopK::new(0);
+        top_none.push(1);
+        assert!(top_none.into_sorted_vec().is_empty());
+
+        let mut top_few = TopK::new(5);
+        top_few.extend([2, 1]);
+        assert_eq!(top_few.into_sorted_vec(), [2, 1]);
+    }
+}
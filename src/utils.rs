@@ -1,10 +1,306 @@
+use crate::fetch::{Fetcher, SESSION_VAR};
 use log::info;
+use std::convert::Infallible;
+use std::env;
+use std::fmt;
 use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::io::{self, BufRead, BufReader, ErrorKind};
+use std::mem;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
 
-pub fn input_for_day(day: i8) -> impl Iterator<Item = String> {
-    let input_filepath = format!("inputs/day-{:02}.txt", day);
-    info!("Using input file - {}", input_filepath);
-    let file: File = File::open(input_filepath).unwrap();
-    return BufReader::new(file).lines().map(|line| line.unwrap());
+pub enum Error {
+    MissingInput {
+        day: u8,
+        path: PathBuf,
+        // Only default inputs are fetched, so only they are worth setting a session for
+        fetchable: bool,
+    },
+    Io {
+        path: PathBuf,
+        source: io::Error,
+    },
+    Fetch {
+        day: u8,
+        url: String,
+        message: String,
+    },
+    Parse {
+        day: u8,
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    InvalidAnswers {
+        path: PathBuf,
+        message: String,
+    },
+    InvalidLedger {
+        path: PathBuf,
+        message: String,
+    },
+    Submit {
+        day: u8,
+        part: u8,
+        message: String,
+    },
+    UnsolvedDay(u8),
+    Usage(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::MissingInput {
+                day,
+                path,
+                fetchable,
+            } => {
+                write!(f, "no input for day {} at {}", day, path.display())?;
+                match fetchable {
+                    true => write!(f, " (set ${} to fetch it)", SESSION_VAR),
+                    false => Ok(()),
+                }
+            }
+            Error::Io { path, source } => {
+                write!(f, "could not read {}: {}", path.display(), source)
+            }
+            Error::Fetch { day, url, message } => {
+                write!(
+                    f,
+                    "could not fetch the input for day {} from {}: {}",
+                    day, url, message
+                )
+            }
+            Error::Parse {
+                day,
+                line,
+                column,
+                message,
+            } => write!(
+                f,
+                "invalid input for day {} at line {}, column {}: {}",
+                day, line, column, message
+            ),
+            Error::InvalidAnswers { path, message } => {
+                write!(f, "invalid answers in {}: {}", path.display(), message)
+            }
+            Error::InvalidLedger { path, message } => {
+                write!(
+                    f,
+                    "invalid submissions ledger {}: {}",
+                    path.display(),
+                    message
+                )
+            }
+            Error::Submit { day, part, message } => {
+                write!(f, "not submitting day {} part {}: {}", day, part, message)
+            }
+            Error::UnsolvedDay(day) => write!(f, "no solution registered for day {}", day),
+            Error::Usage(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+// `main` reports errors through `Debug`, so print the same diagnostic as `Display`
+impl fmt::Debug for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+// An error within a single line of input. Columns are 1-indexed.
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub column: usize,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(column: usize, message: impl Into<String>) -> Self {
+        ParseError {
+            column,
+            message: message.into(),
+        }
+    }
+
+    // For errors from parsers that were only handed a slice of the line
+    pub fn offset(self, columns: usize) -> Self {
+        ParseError {
+            column: self.column + columns,
+            ..self
+        }
+    }
+
+    pub fn on_line(self, day: u8, line_idx: usize) -> Error {
+        Error::Parse {
+            day,
+            line: line_idx + 1,
+            column: self.column,
+            message: self.message,
+        }
+    }
+}
+
+pub fn parse_number<T: FromStr>(token: &str, column: usize) -> Result<T, ParseError> {
+    token
+        .parse::<T>()
+        .map_err(|_| ParseError::new(column, format!("invalid number `{}`", token)))
+}
+
+pub fn split_once<'a>(line: &'a str, delimiter: &str) -> Result<(&'a str, &'a str), ParseError> {
+    line.split_once(delimiter)
+        .ok_or_else(|| ParseError::new(line.len() + 1, format!("expected `{}`", delimiter)))
+}
+
+// Splits `line` on `delimiter`, pairing every token with the column it starts at
+pub fn split_with_columns(line: &str, delimiter: char) -> impl Iterator<Item = (usize, &str)> {
+    line.split(delimiter).scan(1, move |column, token| {
+        let token_column = *column;
+        *column += token.len() + delimiter.len_utf8();
+        Some((token_column, token))
+    })
+}
+
+// Parses each of the (0-indexed, as from `enumerate`) lines, reporting the first failure
+pub fn parse_each_line<T>(
+    day: u8,
+    numbered_lines: impl Iterator<Item = (usize, String)>,
+    mut parser: impl FnMut(&str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, Error> {
+    numbered_lines
+        .map(|(line_idx, line)| parser(&line).map_err(|err| err.on_line(day, line_idx)))
+        .collect()
+}
+
+// Parses groups of lines separated by blank lines, as with `parse_each_line`. Runs of blank lines
+// separate groups too, and those before the first group or after the last are ignored, so the
+// last group doesn't need a blank line after it.
+pub fn parse_line_groups<T>(
+    day: u8,
+    numbered_lines: impl Iterator<Item = (usize, String)>,
+    mut parser: impl FnMut(&str) -> Result<T, ParseError>,
+) -> Result<Vec<Vec<T>>, Error> {
+    let mut groups = Vec::new();
+    let mut group = Vec::new();
+
+    for (line_idx, line) in numbered_lines {
+        if line.trim().is_empty() {
+            if !group.is_empty() {
+                groups.push(mem::take(&mut group));
+            }
+            continue;
+        }
+        group.push(parser(&line).map_err(|err| err.on_line(day, line_idx))?);
+    }
+    if !group.is_empty() {
+        groups.push(group);
+    }
+    Ok(groups)
+}
+
+// Overrides the directory holding the `day-XX.txt` inputs, which is `inputs` by default
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    // `day-XX.txt` in the input directory
+    Default,
+    // Either a single input file or a directory of `day-XX.txt` inputs
+    Path(PathBuf),
+    Stdin,
+    Inline(String),
+}
+
+impl FromStr for InputSource {
+    type Err = Infallible;
+
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        match source {
+            "-" => Ok(InputSource::Stdin),
+            path => Ok(InputSource::Path(PathBuf::from(path))),
+        }
+    }
+}
+
+impl InputSource {
+    // Whether the source holds the input of one day only, and so can't be used for many days
+    pub fn is_single_day(&self) -> bool {
+        match self {
+            InputSource::Default => false,
+            InputSource::Path(path) => !path.is_dir(),
+            InputSource::Stdin | InputSource::Inline(_) => true,
+        }
+    }
+
+    // The file holding the input of `day`, for sources that read one
+    pub fn path(&self, day: u8) -> Option<PathBuf> {
+        match self {
+            InputSource::Default => Some(input_dir().join(input_filename(day))),
+            InputSource::Path(path) if path.is_dir() => Some(path.join(input_filename(day))),
+            InputSource::Path(path) => Some(path.clone()),
+            InputSource::Stdin | InputSource::Inline(_) => None,
+        }
+    }
+
+    pub fn lines(&self, day: u8) -> Result<impl Iterator<Item = String>, Error> {
+        let lines = match (self, self.path(day)) {
+            // Missing inputs are fetched into the input directory when there's a session to do so
+            (InputSource::Default, Some(path)) => {
+                if let Some(fetcher) = Fetcher::from_env() {
+                    fetcher.fetch_into(day, &path)?;
+                }
+                read_input_file(day, &path, true)?
+            }
+            (_, Some(path)) => read_input_file(day, &path, false)?,
+            (InputSource::Inline(input), None) => input.lines().map(String::from).collect(),
+            (_, None) => lines_from_reader(io::stdin().lock(), Path::new("<stdin>"))?,
+        };
+        Ok(lines.into_iter())
+    }
+}
+
+// Reads all lines up front so that I/O errors surface before any parsing happens. `path`
+// only names the reader in errors.
+pub fn lines_from_reader(reader: impl BufRead, path: &Path) -> Result<Vec<String>, Error> {
+    reader
+        .lines()
+        .collect::<Result<Vec<String>, io::Error>>()
+        .map_err(|err| Error::Io {
+            path: path.to_path_buf(),
+            source: err,
+        })
+}
+
+fn input_dir() -> PathBuf {
+    env::var_os(INPUT_DIR_VAR).map_or_else(|| PathBuf::from("inputs"), PathBuf::from)
+}
+
+fn input_filename(day: u8) -> String {
+    format!("day-{:02}.txt", day)
+}
+
+fn read_input_file(day: u8, input_filepath: &Path, fetchable: bool) -> Result<Vec<String>, Error> {
+    info!("Using input file - {}", input_filepath.display());
+    let file: File = File::open(input_filepath).map_err(|err| match err.kind() {
+        ErrorKind::NotFound => Error::MissingInput {
+            day,
+            path: input_filepath.to_path_buf(),
+            fetchable,
+        },
+        _ => Error::Io {
+            path: input_filepath.to_path_buf(),
+            source: err,
+        },
+    })?;
+    lines_from_reader(BufReader::new(file), input_filepath)
 }
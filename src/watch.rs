@@ -0,0 +1,104 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+use std::time::SystemTime;
+
+// Polls the modification times of a set of files, and of every file under a set of directories.
+// Paths that don't exist (yet) are watched too, and count as changed once they're created, as
+// do files added to or removed from a watched directory.
+pub struct Watcher {
+    paths: Vec<PathBuf>,
+    mtimes: BTreeMap<PathBuf, SystemTime>,
+}
+
+impl Watcher {
+    pub fn new(paths: Vec<PathBuf>) -> Self {
+        let mtimes = mtimes(&paths);
+        Watcher { paths, mtimes }
+    }
+
+    pub fn paths(&self) -> &[PathBuf] {
+        &self.paths
+    }
+
+    // The files modified, created or removed since the last poll, in path order
+    pub fn poll(&mut self) -> Vec<PathBuf> {
+        let curr_mtimes = mtimes(&self.paths);
+        let removed = self
+            .mtimes
+            .keys()
+            .filter(|path| !curr_mtimes.contains_key(*path));
+        let mut changed: Vec<PathBuf> = curr_mtimes
+            .iter()
+            .filter(|(path, mtime)| self.mtimes.get(*path) != Some(mtime))
+            .map(|(path, _)| path)
+            .chain(removed)
+            .cloned()
+            .collect();
+        changed.sort();
+        self.mtimes = curr_mtimes;
+        changed
+    }
+}
+
+fn mtimes(paths: &[PathBuf]) -> BTreeMap<PathBuf, SystemTime> {
+    let mut mtimes = BTreeMap::new();
+    let mut to_visit = paths.to_vec();
+    while let Some(path) = to_visit.pop() {
+        let Ok(meta) = fs::metadata(&path) else {
+            continue;
+        };
+        if meta.is_dir() {
+            if let Ok(entries) = fs::read_dir(&path) {
+                to_visit.extend(entries.filter_map(|entry| entry.ok().map(|entry| entry.path())));
+            }
+        } else if let Ok(mtime) = meta.modified() {
+            mtimes.insert(path, mtime);
+        }
+    }
+    mtimes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+    use std::time::Duration;
+
+    #[test]
+    fn reports_modified_and_created_files() {
+        let dir = env::temp_dir().join(format!("aoc-watch-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let (existing, created) = (dir.join("existing.txt"), dir.join("created.txt"));
+        fs::write(&existing, "1").unwrap();
+        let mut watcher = Watcher::new(vec![existing.clone(), created.clone()]);
+        assert!(watcher.poll().is_empty());
+
+        let file = fs::File::options().write(true).open(&existing).unwrap();
+        file.set_modified(SystemTime::now() + Duration::from_secs(1))
+            .unwrap();
+        assert_eq!(watcher.poll(), std::slice::from_ref(&existing));
+
+        fs::write(&created, "2").unwrap();
+        assert_eq!(watcher.poll(), [created]);
+        assert!(watcher.poll().is_empty());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn watches_the_files_under_directories() {
+        let dir = env::temp_dir().join(format!("aoc-watch-dir-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let mut watcher = Watcher::new(vec![dir.clone()]);
+
+        let nested = dir.join("day_02").join("simulation.rs");
+        fs::create_dir_all(nested.parent().unwrap()).unwrap();
+        fs::write(&nested, "1").unwrap();
+        assert_eq!(watcher.poll(), std::slice::from_ref(&nested));
+        assert!(watcher.poll().is_empty());
+
+        fs::remove_file(&nested).unwrap();
+        assert_eq!(watcher.poll(), [nested]);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,48 @@
+// https://adventofcode.com/2022/day/{{DAY}}
+
+use crate::solution::{Answer, Solution};
+use crate::utils::Error;
+
+// Placeholders, which run as `unknown` answers until the day is solved
+pub fn part_one(_lines: &[String]) -> &'static str {
+    "unsolved"
+}
+
+pub fn part_two(_lines: &[String]) -> &'static str {
+    "unsolved"
+}
+
+pub struct Solver;
+
+impl Solution for Solver {
+    const DAY: u8 = {{DAY}};
+    type Input = Vec<String>;
+
+    fn parse(lines: impl Iterator<Item = String>) -> Result<Self::Input, Error> {
+        Ok(lines.collect())
+    }
+
+    fn part_one(input: &Self::Input) -> Answer {
+        Answer::from(part_one(input))
+    }
+
+    fn part_two(input: &Self::Input) -> Answer {
+        Answer::from(part_two(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::solve;
+    use crate::utils::InputSource;
+    use std::path::PathBuf;
+
+    #[test]
+    #[ignore = "fill in the example and its answers from the puzzle"]
+    fn solves_example() {
+        let example = InputSource::Path(PathBuf::from("inputs/examples"));
+        let answers = solve::<Solver>(&example).unwrap().answers;
+        assert_eq!(answers, [Answer::from(0), Answer::from(0)]);
+    }
+}